@@ -0,0 +1,69 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use sequential_integration::{
+    calculate_single_integral_simpson, calculate_triple_integral_simpson,
+    calculate_triple_integral_simpson_with_policy, NonFinitePolicy,
+};
+
+// Same nodes as `triple_integral_simpson`, the difference is the cost of the policy check.
+fn triple_integral_simpson_with_policy(c: &mut Criterion) {
+    c.bench_function("triple_integral_simpson_with_policy", |b| {
+        b.iter(|| {
+            calculate_triple_integral_simpson_with_policy(
+                |x: f64, y: f64, z: f64| x.powf(2.) + y.powf(2.) + z.powf(2.),
+                black_box(-1.),
+                black_box(1.),
+                0.02,
+                |x| x,
+                |x| x / 2.,
+                0.02,
+                |x: f64, y: f64| x.powf(2.) + y,
+                |_x, _y| 0.,
+                0.02,
+                NonFinitePolicy::Propagate,
+            )
+            .unwrap()
+        })
+    });
+}
+
+fn triple_integral_simpson(c: &mut Criterion) {
+    c.bench_function("triple_integral_simpson", |b| {
+        b.iter(|| {
+            calculate_triple_integral_simpson(
+                |x: f64, y: f64, z: f64| x.powf(2.) + y.powf(2.) + z.powf(2.),
+                black_box(-1.),
+                black_box(1.),
+                0.02,
+                |x| x,
+                |x| x / 2.,
+                0.02,
+                |x: f64, y: f64| x.powf(2.) + y,
+                |_x, _y| 0.,
+                0.02,
+            )
+            .unwrap()
+        })
+    });
+}
+
+fn single_integral_simpson(c: &mut Criterion) {
+    c.bench_function("single_integral_simpson", |b| {
+        b.iter(|| {
+            calculate_single_integral_simpson(
+                |x: f64| (1. - x.powf(2.)).sqrt(),
+                black_box(-1.),
+                black_box(1.),
+                0.0001,
+            )
+            .unwrap()
+        })
+    });
+}
+
+criterion_group!(
+    benches,
+    triple_integral_simpson_with_policy,
+    triple_integral_simpson,
+    single_integral_simpson
+);
+criterion_main!(benches);
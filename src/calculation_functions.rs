@@ -1,24 +1,1123 @@
 use fehler::throws;
 
-use crate::{engine, errors::Error};
+use crate::{
+    engine,
+    engine::{
+        function_types::Fallible,
+        quadrature::{
+            gauss_kronrod::GaussKronrodRule, monte_carlo::MonteCarloSampling,
+            newton_cotes::NewtonCotesRule, GetConvergenceOrder,
+        },
+        DimensionBounds, IntegrationOutcome, NonFinitePolicy, Resolution,
+    },
+    errors::Error,
+    integral::{DimensionQuadrature, Rule},
+};
 
 #[throws]
-pub fn calculate_single_integral_simpson<E: Fn(f64) -> f64>(
+pub fn calculate_single_integral_simpson<E: Fn(f64) -> f64, R1: Into<Resolution>>(
     equation: E,
     first_integral_begin: f64,
     first_integral_end: f64,
-    first_integral_step: f64,
+    first_integral_step: R1,
 ) -> f64 {
-    let simpson_quadrature = engine::quadrature::simpson::SimpsonQuadratureSingleIntegral::new(
+    let composite_simpson_quadrature =
+        engine::quadrature::simpson::CompositeSimpsonQuadrature::new(first_integral_step.into())?;
+
+    engine::calculate_single_integral_with_rule(
+        composite_simpson_quadrature,
+        equation,
+        first_integral_begin,
+        first_integral_end,
+    )?
+}
+
+#[throws]
+pub fn calculate_double_integral_simpson<
+    E: Fn(f64, f64) -> f64,
+    F1: Fn(f64) -> f64,
+    F2: Fn(f64) -> f64,
+    R1: Into<Resolution>,
+    R2: Into<Resolution>,
+>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    first_integral_step: R1,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+    second_integral_step: R2,
+) -> f64 {
+    engine::calculate_double_integral_with_rules(
+        engine::quadrature::simpson::CompositeSimpsonQuadrature::new(first_integral_step.into())?,
+        engine::quadrature::simpson::CompositeSimpsonQuadrature::new(second_integral_step.into())?,
+        equation,
+        first_integral_begin,
+        first_integral_end,
+        second_integral_begin,
+        second_integral_end,
+    )?
+}
+
+#[allow(clippy::too_many_arguments)]
+#[throws]
+pub fn calculate_triple_integral_simpson<
+    E: Fn(f64, f64, f64) -> f64,
+    F1: Fn(f64) -> f64,
+    F2: Fn(f64) -> f64,
+    F3: Fn(f64, f64) -> f64,
+    F4: Fn(f64, f64) -> f64,
+    R1: Into<Resolution>,
+    R2: Into<Resolution>,
+    R3: Into<Resolution>,
+>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    first_integral_step: R1,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+    second_integral_step: R2,
+    third_integral_begin: F3,
+    third_integral_end: F4,
+    third_integral_step: R3,
+) -> f64 {
+    engine::calculate_triple_integral_with_rules(
+        engine::quadrature::simpson::CompositeSimpsonQuadrature::new(first_integral_step.into())?,
+        engine::quadrature::simpson::CompositeSimpsonQuadrature::new(second_integral_step.into())?,
+        engine::quadrature::simpson::CompositeSimpsonQuadrature::new(third_integral_step.into())?,
+        equation,
+        first_integral_begin,
+        first_integral_end,
+        second_integral_begin,
+        second_integral_end,
+        third_integral_begin,
+        third_integral_end,
+    )?
+}
+
+#[cfg(feature = "parallel")]
+#[throws]
+pub fn calculate_single_integral_simpson_parallel<
+    E: Fn(f64) -> f64 + Sync,
+    R1: Into<Resolution>,
+>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    first_integral_step: R1,
+) -> f64 {
+    engine::calculate_single_integral_with_rule_parallel(
+        engine::quadrature::simpson::CompositeSimpsonQuadrature::new(first_integral_step.into())?,
+        equation,
+        first_integral_begin,
+        first_integral_end,
+    )?
+}
+
+#[cfg(feature = "parallel")]
+#[throws]
+pub fn calculate_double_integral_simpson_parallel<
+    E: Fn(f64, f64) -> f64 + Sync,
+    F1: Fn(f64) -> f64 + Sync,
+    F2: Fn(f64) -> f64 + Sync,
+    R1: Into<Resolution>,
+    R2: Into<Resolution>,
+>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    first_integral_step: R1,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+    second_integral_step: R2,
+) -> f64 {
+    engine::calculate_double_integral_with_rules_parallel(
+        engine::quadrature::simpson::CompositeSimpsonQuadrature::new(first_integral_step.into())?,
+        engine::quadrature::simpson::CompositeSimpsonQuadrature::new(second_integral_step.into())?,
+        equation,
+        first_integral_begin,
+        first_integral_end,
+        second_integral_begin,
+        second_integral_end,
+    )?
+}
+
+#[cfg(feature = "parallel")]
+#[allow(clippy::too_many_arguments)]
+#[throws]
+pub fn calculate_triple_integral_simpson_parallel<
+    E: Fn(f64, f64, f64) -> f64 + Sync,
+    F1: Fn(f64) -> f64 + Sync,
+    F2: Fn(f64) -> f64 + Sync,
+    F3: Fn(f64, f64) -> f64 + Sync,
+    F4: Fn(f64, f64) -> f64 + Sync,
+    R1: Into<Resolution>,
+    R2: Into<Resolution>,
+    R3: Into<Resolution>,
+>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    first_integral_step: R1,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+    second_integral_step: R2,
+    third_integral_begin: F3,
+    third_integral_end: F4,
+    third_integral_step: R3,
+) -> f64 {
+    engine::calculate_triple_integral_with_rules_parallel(
+        engine::quadrature::simpson::CompositeSimpsonQuadrature::new(first_integral_step.into())?,
+        engine::quadrature::simpson::CompositeSimpsonQuadrature::new(second_integral_step.into())?,
+        engine::quadrature::simpson::CompositeSimpsonQuadrature::new(third_integral_step.into())?,
+        equation,
+        first_integral_begin,
+        first_integral_end,
+        second_integral_begin,
+        second_integral_end,
+        third_integral_begin,
+        third_integral_end,
+    )?
+}
+
+#[throws]
+pub fn calculate_single_integral_simpson_with_policy<E: Fn(f64) -> f64, R1: Into<Resolution>>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    first_integral_step: R1,
+    non_finite_policy: NonFinitePolicy,
+) -> f64 {
+    engine::calculate_single_integral_with_rule(
+        engine::quadrature::simpson::CompositeSimpsonQuadrature::new(first_integral_step.into())?
+            .with_non_finite_policy(non_finite_policy),
+        equation,
+        first_integral_begin,
+        first_integral_end,
+    )?
+}
+
+#[allow(clippy::too_many_arguments)]
+#[throws]
+pub fn calculate_double_integral_simpson_with_policy<
+    E: Fn(f64, f64) -> f64,
+    F1: Fn(f64) -> f64,
+    F2: Fn(f64) -> f64,
+    R1: Into<Resolution>,
+    R2: Into<Resolution>,
+>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    first_integral_step: R1,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+    second_integral_step: R2,
+    non_finite_policy: NonFinitePolicy,
+) -> f64 {
+    engine::calculate_double_integral_with_rules(
+        engine::quadrature::simpson::CompositeSimpsonQuadrature::new(first_integral_step.into())?
+            .with_non_finite_policy(non_finite_policy),
+        engine::quadrature::simpson::CompositeSimpsonQuadrature::new(second_integral_step.into())?
+            .with_non_finite_policy(non_finite_policy),
+        equation,
+        first_integral_begin,
+        first_integral_end,
+        second_integral_begin,
+        second_integral_end,
+    )?
+}
+
+#[allow(clippy::too_many_arguments)]
+#[throws]
+pub fn calculate_triple_integral_simpson_with_policy<
+    E: Fn(f64, f64, f64) -> f64,
+    F1: Fn(f64) -> f64,
+    F2: Fn(f64) -> f64,
+    F3: Fn(f64, f64) -> f64,
+    F4: Fn(f64, f64) -> f64,
+    R1: Into<Resolution>,
+    R2: Into<Resolution>,
+    R3: Into<Resolution>,
+>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    first_integral_step: R1,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+    second_integral_step: R2,
+    third_integral_begin: F3,
+    third_integral_end: F4,
+    third_integral_step: R3,
+    non_finite_policy: NonFinitePolicy,
+) -> f64 {
+    engine::calculate_triple_integral_with_rules(
+        engine::quadrature::simpson::CompositeSimpsonQuadrature::new(first_integral_step.into())?
+            .with_non_finite_policy(non_finite_policy),
+        engine::quadrature::simpson::CompositeSimpsonQuadrature::new(second_integral_step.into())?
+            .with_non_finite_policy(non_finite_policy),
+        engine::quadrature::simpson::CompositeSimpsonQuadrature::new(third_integral_step.into())?
+            .with_non_finite_policy(non_finite_policy),
+        equation,
+        first_integral_begin,
+        first_integral_end,
+        second_integral_begin,
+        second_integral_end,
+        third_integral_begin,
+        third_integral_end,
+    )?
+}
+
+#[throws]
+pub fn calculate_single_integral_simpson_fallible<
+    E: Fn(f64) -> std::result::Result<f64, IE>,
+    IE: std::error::Error + Send + Sync + 'static,
+    R1: Into<Resolution>,
+>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    first_integral_step: R1,
+) -> f64 {
+    engine::calculate_single_integral_with_rule(
+        engine::quadrature::simpson::CompositeSimpsonQuadrature::new(first_integral_step.into())?,
+        Fallible(equation),
+        first_integral_begin,
+        first_integral_end,
+    )?
+}
+
+#[throws]
+pub fn calculate_double_integral_simpson_fallible<
+    E: Fn(f64, f64) -> std::result::Result<f64, IE>,
+    IE: std::error::Error + Send + Sync + 'static,
+    F1: Fn(f64) -> f64,
+    F2: Fn(f64) -> f64,
+    R1: Into<Resolution>,
+    R2: Into<Resolution>,
+>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    first_integral_step: R1,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+    second_integral_step: R2,
+) -> f64 {
+    engine::calculate_double_integral_with_rules(
+        engine::quadrature::simpson::CompositeSimpsonQuadrature::new(first_integral_step.into())?,
+        engine::quadrature::simpson::CompositeSimpsonQuadrature::new(second_integral_step.into())?,
+        Fallible(equation),
+        first_integral_begin,
+        first_integral_end,
+        second_integral_begin,
+        second_integral_end,
+    )?
+}
+
+#[allow(clippy::too_many_arguments)]
+#[throws]
+pub fn calculate_triple_integral_simpson_fallible<
+    E: Fn(f64, f64, f64) -> std::result::Result<f64, IE>,
+    IE: std::error::Error + Send + Sync + 'static,
+    F1: Fn(f64) -> f64,
+    F2: Fn(f64) -> f64,
+    F3: Fn(f64, f64) -> f64,
+    F4: Fn(f64, f64) -> f64,
+    R1: Into<Resolution>,
+    R2: Into<Resolution>,
+    R3: Into<Resolution>,
+>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    first_integral_step: R1,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+    second_integral_step: R2,
+    third_integral_begin: F3,
+    third_integral_end: F4,
+    third_integral_step: R3,
+) -> f64 {
+    engine::calculate_triple_integral_with_rules(
+        engine::quadrature::simpson::CompositeSimpsonQuadrature::new(first_integral_step.into())?,
+        engine::quadrature::simpson::CompositeSimpsonQuadrature::new(second_integral_step.into())?,
+        engine::quadrature::simpson::CompositeSimpsonQuadrature::new(third_integral_step.into())?,
+        Fallible(equation),
+        first_integral_begin,
+        first_integral_end,
+        second_integral_begin,
+        second_integral_end,
+        third_integral_begin,
+        third_integral_end,
+    )?
+}
+
+#[throws]
+pub fn calculate_single_integral_simpson_with_report<E: Fn(f64) -> f64, R1: Into<Resolution>>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    first_integral_step: R1,
+) -> IntegrationOutcome {
+    let first_integral_step = first_integral_step.into();
+    let x_quadrature =
+        engine::quadrature::simpson::CompositeSimpsonQuadrature::new(first_integral_step)?;
+    let coarse_x_quadrature =
+        engine::quadrature::simpson::CompositeSimpsonQuadrature::new(first_integral_step)?.coarse();
+
+    let value = engine::calculate_single_integral_with_rule(
+        &x_quadrature,
+        &equation,
+        first_integral_begin,
+        first_integral_end,
+    )?;
+    let coarse_value = engine::calculate_single_integral_with_rule(
+        &coarse_x_quadrature,
+        &equation,
+        first_integral_begin,
+        first_integral_end,
+    )?;
+
+    IntegrationOutcome::richardson(
+        value,
+        coarse_value,
+        x_quadrature.get_convergence_order(),
+        x_quadrature.evaluations() + coarse_x_quadrature.evaluations(),
+        x_quadrature.panels(),
+    )
+}
+
+#[throws]
+pub fn calculate_double_integral_simpson_with_report<
+    E: Fn(f64, f64) -> f64,
+    F1: Fn(f64) -> f64,
+    F2: Fn(f64) -> f64,
+    R1: Into<Resolution>,
+    R2: Into<Resolution>,
+>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    first_integral_step: R1,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+    second_integral_step: R2,
+) -> IntegrationOutcome {
+    let (first_integral_step, second_integral_step) =
+        (first_integral_step.into(), second_integral_step.into());
+    let x_quadrature =
+        engine::quadrature::simpson::CompositeSimpsonQuadrature::new(first_integral_step)?;
+    let y_quadrature =
+        engine::quadrature::simpson::CompositeSimpsonQuadrature::new(second_integral_step)?;
+    let coarse_x_quadrature =
+        engine::quadrature::simpson::CompositeSimpsonQuadrature::new(first_integral_step)?.coarse();
+    let coarse_y_quadrature =
+        engine::quadrature::simpson::CompositeSimpsonQuadrature::new(second_integral_step)?
+            .coarse();
+
+    let value = engine::calculate_double_integral_with_rules(
+        &x_quadrature,
+        &y_quadrature,
+        &equation,
+        first_integral_begin,
+        first_integral_end,
+        &second_integral_begin,
+        &second_integral_end,
+    )?;
+    let coarse_value = engine::calculate_double_integral_with_rules(
+        &coarse_x_quadrature,
+        &coarse_y_quadrature,
+        &equation,
+        first_integral_begin,
+        first_integral_end,
+        &second_integral_begin,
+        &second_integral_end,
+    )?;
+
+    IntegrationOutcome::richardson(
+        value,
+        coarse_value,
+        y_quadrature.get_convergence_order(),
+        y_quadrature.evaluations() + coarse_y_quadrature.evaluations(),
+        y_quadrature.panels(),
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+#[throws]
+pub fn calculate_triple_integral_simpson_with_report<
+    E: Fn(f64, f64, f64) -> f64,
+    F1: Fn(f64) -> f64,
+    F2: Fn(f64) -> f64,
+    F3: Fn(f64, f64) -> f64,
+    F4: Fn(f64, f64) -> f64,
+    R1: Into<Resolution>,
+    R2: Into<Resolution>,
+    R3: Into<Resolution>,
+>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    first_integral_step: R1,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+    second_integral_step: R2,
+    third_integral_begin: F3,
+    third_integral_end: F4,
+    third_integral_step: R3,
+) -> IntegrationOutcome {
+    let (first_integral_step, second_integral_step, third_integral_step) = (
+        first_integral_step.into(),
+        second_integral_step.into(),
+        third_integral_step.into(),
+    );
+    let x_quadrature =
+        engine::quadrature::simpson::CompositeSimpsonQuadrature::new(first_integral_step)?;
+    let y_quadrature =
+        engine::quadrature::simpson::CompositeSimpsonQuadrature::new(second_integral_step)?;
+    let z_quadrature =
+        engine::quadrature::simpson::CompositeSimpsonQuadrature::new(third_integral_step)?;
+    let coarse_x_quadrature =
+        engine::quadrature::simpson::CompositeSimpsonQuadrature::new(first_integral_step)?.coarse();
+    let coarse_y_quadrature =
+        engine::quadrature::simpson::CompositeSimpsonQuadrature::new(second_integral_step)?
+            .coarse();
+    let coarse_z_quadrature =
+        engine::quadrature::simpson::CompositeSimpsonQuadrature::new(third_integral_step)?.coarse();
+
+    let value = engine::calculate_triple_integral_with_rules(
+        &x_quadrature,
+        &y_quadrature,
+        &z_quadrature,
+        &equation,
+        first_integral_begin,
+        first_integral_end,
+        &second_integral_begin,
+        &second_integral_end,
+        &third_integral_begin,
+        &third_integral_end,
+    )?;
+    let coarse_value = engine::calculate_triple_integral_with_rules(
+        &coarse_x_quadrature,
+        &coarse_y_quadrature,
+        &coarse_z_quadrature,
+        &equation,
+        first_integral_begin,
+        first_integral_end,
+        &second_integral_begin,
+        &second_integral_end,
+        &third_integral_begin,
+        &third_integral_end,
+    )?;
+
+    IntegrationOutcome::richardson(
+        value,
+        coarse_value,
+        z_quadrature.get_convergence_order(),
+        z_quadrature.evaluations() + coarse_z_quadrature.evaluations(),
+        z_quadrature.panels(),
+    )
+}
+
+#[throws]
+pub fn calculate_single_integral_adaptive_simpson<E: Fn(f64) -> f64>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    abs_tol: f64,
+    rel_tol: f64,
+) -> f64 {
+    let adaptive_simpson_quadrature =
+        engine::quadrature::simpson::AdaptiveSimpsonQuadrature::new(abs_tol, rel_tol)?;
+
+    engine::calculate_single_integral_with_rule(
+        adaptive_simpson_quadrature,
+        equation,
+        first_integral_begin,
+        first_integral_end,
+    )?
+}
+
+#[allow(clippy::too_many_arguments)]
+#[throws]
+pub fn calculate_double_integral_adaptive_simpson<
+    E: Fn(f64, f64) -> f64,
+    F1: Fn(f64) -> f64,
+    F2: Fn(f64) -> f64,
+>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+    abs_tol: f64,
+    rel_tol: f64,
+) -> f64 {
+    engine::calculate_double_integral_with_rules(
+        engine::quadrature::simpson::AdaptiveSimpsonQuadrature::new(abs_tol, rel_tol)?,
+        engine::quadrature::simpson::AdaptiveSimpsonQuadrature::new(abs_tol, rel_tol)?,
+        equation,
+        first_integral_begin,
+        first_integral_end,
+        second_integral_begin,
+        second_integral_end,
+    )?
+}
+
+#[allow(clippy::too_many_arguments)]
+#[throws]
+pub fn calculate_triple_integral_adaptive_simpson<
+    E: Fn(f64, f64, f64) -> f64,
+    F1: Fn(f64) -> f64,
+    F2: Fn(f64) -> f64,
+    F3: Fn(f64, f64) -> f64,
+    F4: Fn(f64, f64) -> f64,
+>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+    third_integral_begin: F3,
+    third_integral_end: F4,
+    abs_tol: f64,
+    rel_tol: f64,
+) -> f64 {
+    engine::calculate_triple_integral_with_rules(
+        engine::quadrature::simpson::AdaptiveSimpsonQuadrature::new(abs_tol, rel_tol)?,
+        engine::quadrature::simpson::AdaptiveSimpsonQuadrature::new(abs_tol, rel_tol)?,
+        engine::quadrature::simpson::AdaptiveSimpsonQuadrature::new(abs_tol, rel_tol)?,
+        equation,
+        first_integral_begin,
+        first_integral_end,
+        second_integral_begin,
+        second_integral_end,
+        third_integral_begin,
+        third_integral_end,
+    )?
+}
+
+#[throws]
+pub fn calculate_single_integral_newton_cotes<E: Fn(f64) -> f64, R1: Into<Resolution>>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    first_integral_step: R1,
+    first_integral_rule: NewtonCotesRule,
+) -> f64 {
+    engine::calculate_single_integral_with_rule(
+        engine::quadrature::newton_cotes::CompositeNewtonCotesQuadrature::new(
+            first_integral_step.into(),
+            first_integral_rule,
+        )?,
+        equation,
+        first_integral_begin,
+        first_integral_end,
+    )?
+}
+
+#[allow(clippy::too_many_arguments)]
+#[throws]
+pub fn calculate_double_integral_newton_cotes<
+    E: Fn(f64, f64) -> f64,
+    F1: Fn(f64) -> f64,
+    F2: Fn(f64) -> f64,
+    R1: Into<Resolution>,
+    R2: Into<Resolution>,
+>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    first_integral_step: R1,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+    second_integral_step: R2,
+    first_integral_rule: NewtonCotesRule,
+    second_integral_rule: NewtonCotesRule,
+) -> f64 {
+    engine::calculate_double_integral_with_rules(
+        engine::quadrature::newton_cotes::CompositeNewtonCotesQuadrature::new(
+            first_integral_step.into(),
+            first_integral_rule,
+        )?,
+        engine::quadrature::newton_cotes::CompositeNewtonCotesQuadrature::new(
+            second_integral_step.into(),
+            second_integral_rule,
+        )?,
+        equation,
+        first_integral_begin,
+        first_integral_end,
+        second_integral_begin,
+        second_integral_end,
+    )?
+}
+
+#[allow(clippy::too_many_arguments)]
+#[throws]
+pub fn calculate_triple_integral_newton_cotes<
+    E: Fn(f64, f64, f64) -> f64,
+    F1: Fn(f64) -> f64,
+    F2: Fn(f64) -> f64,
+    F3: Fn(f64, f64) -> f64,
+    F4: Fn(f64, f64) -> f64,
+    R1: Into<Resolution>,
+    R2: Into<Resolution>,
+    R3: Into<Resolution>,
+>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    first_integral_step: R1,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+    second_integral_step: R2,
+    third_integral_begin: F3,
+    third_integral_end: F4,
+    third_integral_step: R3,
+    first_integral_rule: NewtonCotesRule,
+    second_integral_rule: NewtonCotesRule,
+    third_integral_rule: NewtonCotesRule,
+) -> f64 {
+    engine::calculate_triple_integral_with_rules(
+        engine::quadrature::newton_cotes::CompositeNewtonCotesQuadrature::new(
+            first_integral_step.into(),
+            first_integral_rule,
+        )?,
+        engine::quadrature::newton_cotes::CompositeNewtonCotesQuadrature::new(
+            second_integral_step.into(),
+            second_integral_rule,
+        )?,
+        engine::quadrature::newton_cotes::CompositeNewtonCotesQuadrature::new(
+            third_integral_step.into(),
+            third_integral_rule,
+        )?,
+        equation,
+        first_integral_begin,
+        first_integral_end,
+        second_integral_begin,
+        second_integral_end,
+        third_integral_begin,
+        third_integral_end,
+    )?
+}
+
+#[throws]
+pub fn calculate_single_integral_gauss_legendre<E: Fn(f64) -> f64, R1: Into<Resolution>>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    first_integral_step: R1,
+    points: usize,
+) -> f64 {
+    engine::calculate_single_integral_with_rule(
+        engine::quadrature::gauss_legendre::CompositeGaussLegendreQuadrature::new(
+            first_integral_step.into(),
+            points,
+        )?,
+        equation,
+        first_integral_begin,
+        first_integral_end,
+    )?
+}
+
+#[allow(clippy::too_many_arguments)]
+#[throws]
+pub fn calculate_double_integral_gauss_legendre<
+    E: Fn(f64, f64) -> f64,
+    F1: Fn(f64) -> f64,
+    F2: Fn(f64) -> f64,
+    R1: Into<Resolution>,
+    R2: Into<Resolution>,
+>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    first_integral_step: R1,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+    second_integral_step: R2,
+    points: usize,
+) -> f64 {
+    engine::calculate_double_integral_with_rules(
+        engine::quadrature::gauss_legendre::CompositeGaussLegendreQuadrature::new(
+            first_integral_step.into(),
+            points,
+        )?,
+        engine::quadrature::gauss_legendre::CompositeGaussLegendreQuadrature::new(
+            second_integral_step.into(),
+            points,
+        )?,
+        equation,
+        first_integral_begin,
+        first_integral_end,
+        second_integral_begin,
+        second_integral_end,
+    )?
+}
+
+#[allow(clippy::too_many_arguments)]
+#[throws]
+pub fn calculate_triple_integral_gauss_legendre<
+    E: Fn(f64, f64, f64) -> f64,
+    F1: Fn(f64) -> f64,
+    F2: Fn(f64) -> f64,
+    F3: Fn(f64, f64) -> f64,
+    F4: Fn(f64, f64) -> f64,
+    R1: Into<Resolution>,
+    R2: Into<Resolution>,
+    R3: Into<Resolution>,
+>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    first_integral_step: R1,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+    second_integral_step: R2,
+    third_integral_begin: F3,
+    third_integral_end: F4,
+    third_integral_step: R3,
+    points: usize,
+) -> f64 {
+    engine::calculate_triple_integral_with_rules(
+        engine::quadrature::gauss_legendre::CompositeGaussLegendreQuadrature::new(
+            first_integral_step.into(),
+            points,
+        )?,
+        engine::quadrature::gauss_legendre::CompositeGaussLegendreQuadrature::new(
+            second_integral_step.into(),
+            points,
+        )?,
+        engine::quadrature::gauss_legendre::CompositeGaussLegendreQuadrature::new(
+            third_integral_step.into(),
+            points,
+        )?,
+        equation,
+        first_integral_begin,
+        first_integral_end,
+        second_integral_begin,
+        second_integral_end,
+        third_integral_begin,
+        third_integral_end,
+    )?
+}
+
+#[throws]
+pub fn calculate_single_integral_trapezoid<E: Fn(f64) -> f64, R1: Into<Resolution>>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    first_integral_step: R1,
+) -> f64 {
+    engine::calculate_single_integral_with_rule(
+        engine::quadrature::trapezoid::CompositeTrapezoidQuadrature::new(
+            first_integral_step.into(),
+        )?,
+        equation,
+        first_integral_begin,
+        first_integral_end,
+    )?
+}
+
+#[throws]
+pub fn calculate_double_integral_trapezoid<
+    E: Fn(f64, f64) -> f64,
+    F1: Fn(f64) -> f64,
+    F2: Fn(f64) -> f64,
+    R1: Into<Resolution>,
+    R2: Into<Resolution>,
+>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    first_integral_step: R1,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+    second_integral_step: R2,
+) -> f64 {
+    engine::calculate_double_integral_with_rules(
+        engine::quadrature::trapezoid::CompositeTrapezoidQuadrature::new(
+            first_integral_step.into(),
+        )?,
+        engine::quadrature::trapezoid::CompositeTrapezoidQuadrature::new(
+            second_integral_step.into(),
+        )?,
+        equation,
+        first_integral_begin,
+        first_integral_end,
+        second_integral_begin,
+        second_integral_end,
+    )?
+}
+
+#[allow(clippy::too_many_arguments)]
+#[throws]
+pub fn calculate_triple_integral_trapezoid<
+    E: Fn(f64, f64, f64) -> f64,
+    F1: Fn(f64) -> f64,
+    F2: Fn(f64) -> f64,
+    F3: Fn(f64, f64) -> f64,
+    F4: Fn(f64, f64) -> f64,
+    R1: Into<Resolution>,
+    R2: Into<Resolution>,
+    R3: Into<Resolution>,
+>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    first_integral_step: R1,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+    second_integral_step: R2,
+    third_integral_begin: F3,
+    third_integral_end: F4,
+    third_integral_step: R3,
+) -> f64 {
+    engine::calculate_triple_integral_with_rules(
+        engine::quadrature::trapezoid::CompositeTrapezoidQuadrature::new(
+            first_integral_step.into(),
+        )?,
+        engine::quadrature::trapezoid::CompositeTrapezoidQuadrature::new(
+            second_integral_step.into(),
+        )?,
+        engine::quadrature::trapezoid::CompositeTrapezoidQuadrature::new(
+            third_integral_step.into(),
+        )?,
         equation,
-        first_integral_step,
+        first_integral_begin,
+        first_integral_end,
+        second_integral_begin,
+        second_integral_end,
+        third_integral_begin,
+        third_integral_end,
+    )?
+}
+
+#[throws]
+pub fn calculate_single_integral_midpoint<E: Fn(f64) -> f64, R1: Into<Resolution>>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    first_integral_step: R1,
+) -> f64 {
+    engine::calculate_single_integral_with_rule(
+        engine::quadrature::midpoint::CompositeMidpointQuadrature::new(first_integral_step.into())?,
+        equation,
+        first_integral_begin,
+        first_integral_end,
+    )?
+}
+
+#[throws]
+pub fn calculate_double_integral_midpoint<
+    E: Fn(f64, f64) -> f64,
+    F1: Fn(f64) -> f64,
+    F2: Fn(f64) -> f64,
+    R1: Into<Resolution>,
+    R2: Into<Resolution>,
+>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    first_integral_step: R1,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+    second_integral_step: R2,
+) -> f64 {
+    engine::calculate_double_integral_with_rules(
+        engine::quadrature::midpoint::CompositeMidpointQuadrature::new(first_integral_step.into())?,
+        engine::quadrature::midpoint::CompositeMidpointQuadrature::new(
+            second_integral_step.into(),
+        )?,
+        equation,
+        first_integral_begin,
+        first_integral_end,
+        second_integral_begin,
+        second_integral_end,
+    )?
+}
+
+#[allow(clippy::too_many_arguments)]
+#[throws]
+pub fn calculate_triple_integral_midpoint<
+    E: Fn(f64, f64, f64) -> f64,
+    F1: Fn(f64) -> f64,
+    F2: Fn(f64) -> f64,
+    F3: Fn(f64, f64) -> f64,
+    F4: Fn(f64, f64) -> f64,
+    R1: Into<Resolution>,
+    R2: Into<Resolution>,
+    R3: Into<Resolution>,
+>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    first_integral_step: R1,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+    second_integral_step: R2,
+    third_integral_begin: F3,
+    third_integral_end: F4,
+    third_integral_step: R3,
+) -> f64 {
+    engine::calculate_triple_integral_with_rules(
+        engine::quadrature::midpoint::CompositeMidpointQuadrature::new(first_integral_step.into())?,
+        engine::quadrature::midpoint::CompositeMidpointQuadrature::new(
+            second_integral_step.into(),
+        )?,
+        engine::quadrature::midpoint::CompositeMidpointQuadrature::new(third_integral_step.into())?,
+        equation,
+        first_integral_begin,
+        first_integral_end,
+        second_integral_begin,
+        second_integral_end,
+        third_integral_begin,
+        third_integral_end,
+    )?
+}
+
+#[allow(clippy::too_many_arguments)]
+#[throws]
+pub fn calculate_single_integral_gauss_kronrod<E: Fn(f64) -> f64>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    abs_tol: f64,
+    rel_tol: f64,
+    rule: GaussKronrodRule,
+    limit: usize,
+) -> f64 {
+    let gauss_kronrod_quadrature = engine::quadrature::gauss_kronrod::GaussKronrodQuadrature::new(
+        rule, abs_tol, rel_tol, limit,
+    )?;
+
+    engine::calculate_single_integral_with_rule(
+        gauss_kronrod_quadrature,
+        equation,
+        first_integral_begin,
+        first_integral_end,
+    )?
+}
+
+#[allow(clippy::too_many_arguments)]
+#[throws]
+pub fn calculate_single_integral_principal_value<E: Fn(f64) -> f64>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    pole: f64,
+    abs_tol: f64,
+    rel_tol: f64,
+    rule: GaussKronrodRule,
+    limit: usize,
+) -> f64 {
+    let gauss_kronrod_quadrature = engine::quadrature::gauss_kronrod::GaussKronrodQuadrature::new(
+        rule, abs_tol, rel_tol, limit,
+    )?;
+
+    engine::calculate_principal_value_with_rule(
+        gauss_kronrod_quadrature,
+        equation,
+        first_integral_begin,
+        first_integral_end,
+        pole,
+    )?
+}
+
+#[allow(clippy::too_many_arguments)]
+#[throws]
+pub fn calculate_double_integral_gauss_kronrod<
+    E: Fn(f64, f64) -> f64,
+    F1: Fn(f64) -> f64,
+    F2: Fn(f64) -> f64,
+>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+    abs_tol: f64,
+    rel_tol: f64,
+    rule: GaussKronrodRule,
+    limit: usize,
+) -> f64 {
+    let gauss_kronrod_quadrature = engine::quadrature::gauss_kronrod::GaussKronrodQuadrature::new(
+        rule, abs_tol, rel_tol, limit,
+    )?;
+
+    engine::calculate_double_integral_with_rules(
+        gauss_kronrod_quadrature,
+        gauss_kronrod_quadrature,
+        equation,
+        first_integral_begin,
+        first_integral_end,
+        second_integral_begin,
+        second_integral_end,
+    )?
+}
+
+#[allow(clippy::too_many_arguments)]
+#[throws]
+pub fn calculate_triple_integral_gauss_kronrod<
+    E: Fn(f64, f64, f64) -> f64,
+    F1: Fn(f64) -> f64,
+    F2: Fn(f64) -> f64,
+    F3: Fn(f64, f64) -> f64,
+    F4: Fn(f64, f64) -> f64,
+>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+    third_integral_begin: F3,
+    third_integral_end: F4,
+    abs_tol: f64,
+    rel_tol: f64,
+    rule: GaussKronrodRule,
+    limit: usize,
+) -> f64 {
+    let gauss_kronrod_quadrature = engine::quadrature::gauss_kronrod::GaussKronrodQuadrature::new(
+        rule, abs_tol, rel_tol, limit,
     )?;
 
-    engine::calculate_single_integral(simpson_quadrature, first_integral_begin, first_integral_end)?
+    engine::calculate_triple_integral_with_rules(
+        gauss_kronrod_quadrature,
+        gauss_kronrod_quadrature,
+        gauss_kronrod_quadrature,
+        equation,
+        first_integral_begin,
+        first_integral_end,
+        second_integral_begin,
+        second_integral_end,
+        third_integral_begin,
+        third_integral_end,
+    )?
+}
+
+#[throws]
+pub fn calculate_single_integral_tanh_sinh<E: Fn(f64) -> f64>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    abs_tol: f64,
+    rel_tol: f64,
+    max_level: usize,
+) -> f64 {
+    engine::calculate_single_integral_with_rule(
+        engine::quadrature::tanh_sinh::TanhSinhQuadrature::new(abs_tol, rel_tol, max_level)?,
+        equation,
+        first_integral_begin,
+        first_integral_end,
+    )?
 }
 
+#[allow(clippy::too_many_arguments)]
 #[throws]
-pub fn calculate_double_integral_simpson<
+pub fn calculate_double_integral_tanh_sinh<
     E: Fn(f64, f64) -> f64,
     F1: Fn(f64) -> f64,
     F2: Fn(f64) -> f64,
@@ -26,28 +1125,117 @@ pub fn calculate_double_integral_simpson<
     equation: E,
     first_integral_begin: f64,
     first_integral_end: f64,
-    first_integral_step: f64,
     second_integral_begin: F1,
     second_integral_end: F2,
-    second_integral_step: f64,
+    abs_tol: f64,
+    rel_tol: f64,
+    max_level: usize,
 ) -> f64 {
-    let simpson_quadrature = engine::quadrature::simpson::SimpsonQuadratureDoubleIntegral::new(
+    let tanh_sinh_quadrature =
+        engine::quadrature::tanh_sinh::TanhSinhQuadrature::new(abs_tol, rel_tol, max_level)?;
+
+    engine::calculate_double_integral_with_rules(
+        tanh_sinh_quadrature,
+        tanh_sinh_quadrature,
         equation,
-        first_integral_step,
-        second_integral_step,
-    )?;
+        first_integral_begin,
+        first_integral_end,
+        second_integral_begin,
+        second_integral_end,
+    )?
+}
+
+#[allow(clippy::too_many_arguments)]
+#[throws]
+pub fn calculate_triple_integral_tanh_sinh<
+    E: Fn(f64, f64, f64) -> f64,
+    F1: Fn(f64) -> f64,
+    F2: Fn(f64) -> f64,
+    F3: Fn(f64, f64) -> f64,
+    F4: Fn(f64, f64) -> f64,
+>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+    third_integral_begin: F3,
+    third_integral_end: F4,
+    abs_tol: f64,
+    rel_tol: f64,
+    max_level: usize,
+) -> f64 {
+    let tanh_sinh_quadrature =
+        engine::quadrature::tanh_sinh::TanhSinhQuadrature::new(abs_tol, rel_tol, max_level)?;
 
-    engine::calculate_double_integral(
-        simpson_quadrature,
+    engine::calculate_triple_integral_with_rules(
+        tanh_sinh_quadrature,
+        tanh_sinh_quadrature,
+        tanh_sinh_quadrature,
+        equation,
         first_integral_begin,
         first_integral_end,
         second_integral_begin,
         second_integral_end,
+        third_integral_begin,
+        third_integral_end,
     )?
 }
 
 #[throws]
-pub fn calculate_triple_integral_simpson<
+pub fn calculate_single_integral_clenshaw_curtis<E: Fn(f64) -> f64>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    abs_tol: f64,
+    rel_tol: f64,
+    max_level: usize,
+) -> f64 {
+    engine::calculate_single_integral_with_rule(
+        engine::quadrature::clenshaw_curtis::ClenshawCurtisQuadrature::new(
+            abs_tol, rel_tol, max_level,
+        )?,
+        equation,
+        first_integral_begin,
+        first_integral_end,
+    )?
+}
+
+#[allow(clippy::too_many_arguments)]
+#[throws]
+pub fn calculate_double_integral_clenshaw_curtis<
+    E: Fn(f64, f64) -> f64,
+    F1: Fn(f64) -> f64,
+    F2: Fn(f64) -> f64,
+>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+    abs_tol: f64,
+    rel_tol: f64,
+    max_level: usize,
+) -> f64 {
+    let clenshaw_curtis_quadrature =
+        engine::quadrature::clenshaw_curtis::ClenshawCurtisQuadrature::new(
+            abs_tol, rel_tol, max_level,
+        )?;
+
+    engine::calculate_double_integral_with_rules(
+        &clenshaw_curtis_quadrature,
+        &clenshaw_curtis_quadrature,
+        equation,
+        first_integral_begin,
+        first_integral_end,
+        second_integral_begin,
+        second_integral_end,
+    )?
+}
+
+#[allow(clippy::too_many_arguments)]
+#[throws]
+pub fn calculate_triple_integral_clenshaw_curtis<
     E: Fn(f64, f64, f64) -> f64,
     F1: Fn(f64) -> f64,
     F2: Fn(f64) -> f64,
@@ -57,23 +1245,136 @@ pub fn calculate_triple_integral_simpson<
     equation: E,
     first_integral_begin: f64,
     first_integral_end: f64,
-    first_integral_step: f64,
     second_integral_begin: F1,
     second_integral_end: F2,
-    second_integral_step: f64,
     third_integral_begin: F3,
     third_integral_end: F4,
-    third_integral_step: f64,
+    abs_tol: f64,
+    rel_tol: f64,
+    max_level: usize,
+) -> f64 {
+    let clenshaw_curtis_quadrature =
+        engine::quadrature::clenshaw_curtis::ClenshawCurtisQuadrature::new(
+            abs_tol, rel_tol, max_level,
+        )?;
+
+    engine::calculate_triple_integral_with_rules(
+        &clenshaw_curtis_quadrature,
+        &clenshaw_curtis_quadrature,
+        &clenshaw_curtis_quadrature,
+        equation,
+        first_integral_begin,
+        first_integral_end,
+        second_integral_begin,
+        second_integral_end,
+        third_integral_begin,
+        third_integral_end,
+    )?
+}
+
+#[throws]
+pub fn calculate_single_integral_romberg<E: Fn(f64) -> f64>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    abs_tol: f64,
+    rel_tol: f64,
+    max_level: usize,
 ) -> f64 {
-    let simpson_quadrature = engine::quadrature::simpson::SimpsonQuadratureTripleIntegral::new(
+    engine::calculate_single_integral_with_rule(
+        engine::quadrature::romberg::RombergQuadrature::new(abs_tol, rel_tol, max_level)?,
+        equation,
+        first_integral_begin,
+        first_integral_end,
+    )?
+}
+
+#[throws]
+pub fn calculate_single_integral_romberg_with_diagonal<E: Fn(f64) -> f64>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    abs_tol: f64,
+    rel_tol: f64,
+    max_level: usize,
+) -> (f64, Vec<f64>) {
+    let romberg_quadrature =
+        engine::quadrature::romberg::RombergQuadrature::new(abs_tol, rel_tol, max_level)?;
+
+    let value = engine::calculate_single_integral_with_rule(
+        &romberg_quadrature,
         equation,
-        first_integral_step,
-        second_integral_step,
-        third_integral_step,
+        first_integral_begin,
+        first_integral_end,
     )?;
+    let direction_coeff = if first_integral_begin > first_integral_end {
+        -1.
+    } else {
+        1.
+    };
+
+    (
+        value,
+        romberg_quadrature
+            .diagonal()
+            .into_iter()
+            .map(|entry| entry * direction_coeff)
+            .collect(),
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+#[throws]
+pub fn calculate_double_integral_romberg<
+    E: Fn(f64, f64) -> f64,
+    F1: Fn(f64) -> f64,
+    F2: Fn(f64) -> f64,
+>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+    abs_tol: f64,
+    rel_tol: f64,
+    max_level: usize,
+) -> f64 {
+    engine::calculate_double_integral_with_rules(
+        engine::quadrature::romberg::RombergQuadrature::new(abs_tol, rel_tol, max_level)?,
+        engine::quadrature::romberg::RombergQuadrature::new(abs_tol, rel_tol, max_level)?,
+        equation,
+        first_integral_begin,
+        first_integral_end,
+        second_integral_begin,
+        second_integral_end,
+    )?
+}
 
-    engine::calculate_triple_integral(
-        simpson_quadrature,
+#[allow(clippy::too_many_arguments)]
+#[throws]
+pub fn calculate_triple_integral_romberg<
+    E: Fn(f64, f64, f64) -> f64,
+    F1: Fn(f64) -> f64,
+    F2: Fn(f64) -> f64,
+    F3: Fn(f64, f64) -> f64,
+    F4: Fn(f64, f64) -> f64,
+>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+    third_integral_begin: F3,
+    third_integral_end: F4,
+    abs_tol: f64,
+    rel_tol: f64,
+    max_level: usize,
+) -> f64 {
+    engine::calculate_triple_integral_with_rules(
+        engine::quadrature::romberg::RombergQuadrature::new(abs_tol, rel_tol, max_level)?,
+        engine::quadrature::romberg::RombergQuadrature::new(abs_tol, rel_tol, max_level)?,
+        engine::quadrature::romberg::RombergQuadrature::new(abs_tol, rel_tol, max_level)?,
+        equation,
         first_integral_begin,
         first_integral_end,
         second_integral_begin,
@@ -82,3 +1383,221 @@ pub fn calculate_triple_integral_simpson<
         third_integral_end,
     )?
 }
+
+#[throws]
+pub fn calculate_single_integral_gauss_laguerre<E: Fn(f64) -> f64>(
+    equation: E,
+    points: usize,
+    alpha: f64,
+) -> f64 {
+    engine::quadrature::gauss_laguerre::GaussLaguerreQuadrature::new(points, alpha)?
+        .integrate_single(&equation)?
+}
+
+#[throws]
+pub fn calculate_double_integral_gauss_laguerre<E: Fn(f64, f64) -> f64>(
+    equation: E,
+    points: usize,
+    alpha: f64,
+) -> f64 {
+    engine::quadrature::gauss_laguerre::GaussLaguerreQuadrature::new(points, alpha)?
+        .integrate_double(&equation)?
+}
+
+#[throws]
+pub fn calculate_triple_integral_gauss_laguerre<E: Fn(f64, f64, f64) -> f64>(
+    equation: E,
+    points: usize,
+    alpha: f64,
+) -> f64 {
+    engine::quadrature::gauss_laguerre::GaussLaguerreQuadrature::new(points, alpha)?
+        .integrate_triple(&equation)?
+}
+
+#[throws]
+pub fn calculate_single_integral_gauss_hermite<E: Fn(f64) -> f64>(
+    equation: E,
+    points: usize,
+) -> f64 {
+    engine::quadrature::gauss_hermite::GaussHermiteQuadrature::new(points)?
+        .integrate_single(&equation)?
+}
+
+#[throws]
+pub fn calculate_double_integral_gauss_hermite<E: Fn(f64, f64) -> f64>(
+    equation: E,
+    points: usize,
+) -> f64 {
+    engine::quadrature::gauss_hermite::GaussHermiteQuadrature::new(points)?
+        .integrate_double(&equation)?
+}
+
+#[throws]
+pub fn calculate_triple_integral_gauss_hermite<E: Fn(f64, f64, f64) -> f64>(
+    equation: E,
+    points: usize,
+) -> f64 {
+    engine::quadrature::gauss_hermite::GaussHermiteQuadrature::new(points)?
+        .integrate_triple(&equation)?
+}
+
+#[throws]
+pub fn calculate_single_integral_gauss_jacobi<E: Fn(f64) -> f64>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    points: usize,
+    alpha: f64,
+    beta: f64,
+) -> f64 {
+    engine::calculate_single_integral_with_rule(
+        engine::quadrature::gauss_jacobi::GaussJacobiQuadrature::new(points, alpha, beta)?,
+        equation,
+        first_integral_begin,
+        first_integral_end,
+    )?
+}
+
+#[allow(clippy::too_many_arguments)]
+#[throws]
+pub fn calculate_double_integral_gauss_jacobi<
+    E: Fn(f64, f64) -> f64,
+    F1: Fn(f64) -> f64,
+    F2: Fn(f64) -> f64,
+>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+    first_integral_rule: Rule,
+    points: usize,
+    alpha: f64,
+    beta: f64,
+) -> f64 {
+    engine::calculate_double_integral_with_rules(
+        DimensionQuadrature::new(first_integral_rule)?,
+        engine::quadrature::gauss_jacobi::GaussJacobiQuadrature::new(points, alpha, beta)?,
+        equation,
+        first_integral_begin,
+        first_integral_end,
+        second_integral_begin,
+        second_integral_end,
+    )?
+}
+
+#[throws]
+pub fn calculate_single_integral_monte_carlo<E: Fn(f64) -> f64>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    samples: usize,
+    seed: u64,
+    sampling: MonteCarloSampling,
+) -> IntegrationOutcome {
+    engine::quadrature::monte_carlo::MonteCarloQuadrature::new(samples, seed, sampling)?
+        .integrate_single(&equation, first_integral_begin, first_integral_end)?
+}
+
+#[allow(clippy::too_many_arguments)]
+#[throws]
+pub fn calculate_double_integral_monte_carlo<
+    E: Fn(f64, f64) -> f64,
+    F1: Fn(f64) -> f64,
+    F2: Fn(f64) -> f64,
+>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+    samples: usize,
+    seed: u64,
+    sampling: MonteCarloSampling,
+) -> IntegrationOutcome {
+    engine::quadrature::monte_carlo::MonteCarloQuadrature::new(samples, seed, sampling)?
+        .integrate_double(
+            &equation,
+            first_integral_begin,
+            first_integral_end,
+            second_integral_begin,
+            second_integral_end,
+        )?
+}
+
+#[allow(clippy::too_many_arguments)]
+#[throws]
+pub fn calculate_triple_integral_monte_carlo<
+    E: Fn(f64, f64, f64) -> f64,
+    F1: Fn(f64) -> f64,
+    F2: Fn(f64) -> f64,
+    F3: Fn(f64, f64) -> f64,
+    F4: Fn(f64, f64) -> f64,
+>(
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+    third_integral_begin: F3,
+    third_integral_end: F4,
+    samples: usize,
+    seed: u64,
+    sampling: MonteCarloSampling,
+) -> IntegrationOutcome {
+    engine::quadrature::monte_carlo::MonteCarloQuadrature::new(samples, seed, sampling)?
+        .integrate_triple(
+            &equation,
+            first_integral_begin,
+            first_integral_end,
+            second_integral_begin,
+            second_integral_end,
+            third_integral_begin,
+            third_integral_end,
+        )?
+}
+
+#[throws]
+pub fn calculate_multiple_integral_adaptive_simpson<E: Fn(&[f64; N]) -> f64, const N: usize>(
+    equation: E,
+    dimensions: &[DimensionBounds; N],
+    abs_tol: f64,
+    rel_tol: f64,
+) -> f64 {
+    let adaptive_simpson_quadrature =
+        engine::quadrature::simpson::AdaptiveSimpsonQuadrature::new(abs_tol, rel_tol)?;
+
+    engine::calculate_multiple_integral_with_rule(
+        adaptive_simpson_quadrature,
+        equation,
+        dimensions,
+    )?
+}
+
+#[throws]
+pub fn calculate_multiple_integral_gauss_kronrod<E: Fn(&[f64; N]) -> f64, const N: usize>(
+    equation: E,
+    dimensions: &[DimensionBounds; N],
+    abs_tol: f64,
+    rel_tol: f64,
+    rule: GaussKronrodRule,
+    limit: usize,
+) -> f64 {
+    let gauss_kronrod_quadrature = engine::quadrature::gauss_kronrod::GaussKronrodQuadrature::new(
+        rule, abs_tol, rel_tol, limit,
+    )?;
+
+    engine::calculate_multiple_integral_with_rule(gauss_kronrod_quadrature, equation, dimensions)?
+}
+
+#[throws]
+pub fn calculate_multiple_integral_monte_carlo<E: Fn(&[f64; N]) -> f64, const N: usize>(
+    equation: E,
+    dimensions: &[DimensionBounds; N],
+    samples: usize,
+    seed: u64,
+    sampling: MonteCarloSampling,
+) -> IntegrationOutcome {
+    engine::quadrature::monte_carlo::MonteCarloQuadrature::new(samples, seed, sampling)?
+        .integrate_multiple(&equation, dimensions)?
+}
@@ -3,7 +3,8 @@ use snafu::ensure;
 
 use crate::errors::{self, Error};
 
-#[derive(Copy, Clone)]
+/// Finite bounds of one dimension with `begin <= end`.
+#[derive(Debug, Copy, Clone)]
 pub struct Bounds {
     pub begin: f64,
     pub end: f64,
@@ -12,6 +13,8 @@ pub struct Bounds {
 impl Bounds {
     #[throws]
     pub fn new(begin: f64, end: f64) -> Self {
+        ensure!(begin.is_finite(), errors::NonFiniteBound { bound: begin });
+        ensure!(end.is_finite(), errors::NonFiniteBound { bound: end });
         ensure!(
             begin <= end,
             errors::BeginBoundGreaterThanEndBound { begin, end }
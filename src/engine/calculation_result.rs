@@ -1,7 +1,10 @@
-#[derive(Debug, Copy, Clone)]
+/// Partial sums of panels, `common` is scaled in `FinalizeCalculation`, `last` is final.
+#[derive(Debug, Default, Copy, Clone)]
 pub struct CalculationResult {
     pub common: f64,
     pub last: f64,
+    pub evaluations: usize,
+    pub panels: usize,
 }
 
 impl std::ops::AddAssign for CalculationResult {
@@ -9,6 +12,8 @@ impl std::ops::AddAssign for CalculationResult {
         *self = Self {
             common: self.common + other.common,
             last: self.last + other.last,
+            evaluations: self.evaluations + other.evaluations,
+            panels: self.panels + other.panels,
         };
     }
 }
@@ -28,6 +33,8 @@ impl CalculationResult {
         Self {
             common: 0.,
             last: 0.,
+            evaluations: 0,
+            panels: 0,
         }
     }
 
@@ -38,4 +45,9 @@ impl CalculationResult {
     pub fn add_last(&mut self, value: f64) {
         self.last += value;
     }
+
+    pub fn count_panel(&mut self, evaluations: usize) {
+        self.evaluations += evaluations;
+        self.panels += 1;
+    }
 }
@@ -1,3 +1,4 @@
+/// Beginning of a panel, `Last` marks the final panel which may be shorter than the others.
 #[derive(Debug, Copy, Clone)]
 pub enum CalculationStep {
     Common(f64),
@@ -17,9 +18,6 @@ impl std::ops::Deref for CalculationStep {
 
 impl CalculationStep {
     pub fn is_last(&self) -> bool {
-        match self {
-            CalculationStep::Last(_) => true,
-            _ => false,
-        }
+        matches!(self, CalculationStep::Last(_))
     }
 }
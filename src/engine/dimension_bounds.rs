@@ -0,0 +1,19 @@
+use crate::engine::function_types::OuterVariablesFunction;
+
+pub struct DimensionBounds<'a> {
+    pub begin: OuterVariablesFunction<'a>,
+    pub end: OuterVariablesFunction<'a>,
+}
+
+impl<'a> DimensionBounds<'a> {
+    pub fn new<F1: Fn(&[f64]) -> f64 + 'a, F2: Fn(&[f64]) -> f64 + 'a>(begin: F1, end: F2) -> Self {
+        Self {
+            begin: Box::new(begin),
+            end: Box::new(end),
+        }
+    }
+
+    pub fn constant(begin: f64, end: f64) -> Self {
+        Self::new(move |_| begin, move |_| end)
+    }
+}
@@ -1,5 +1,71 @@
-// https://doc.rust-lang.org/beta/unstable-book/library-features/fn-traits.html
+use snafu::ResultExt;
 
-// pub trait OneVariableFunction: Fn(f64) -> f64 {}
-// pub trait TwoVariableFunction: Fn(f64, f64) -> f64 {}
-// pub trait ThreeVariableFunction: Fn(f64, f64, f64) -> f64 {}
+use crate::errors::{self, Result};
+
+pub trait OneVariableFunction {
+    fn calculate(&self, x: f64) -> Result<f64>;
+}
+
+pub trait TwoVariableFunction {
+    fn calculate(&self, x: f64, y: f64) -> Result<f64>;
+}
+
+pub trait ThreeVariableFunction {
+    fn calculate(&self, x: f64, y: f64, z: f64) -> Result<f64>;
+}
+
+impl<F: Fn(f64) -> f64> OneVariableFunction for F {
+    fn calculate(&self, x: f64) -> Result<f64> {
+        Ok(self(x))
+    }
+}
+
+impl<F: Fn(f64, f64) -> f64> TwoVariableFunction for F {
+    fn calculate(&self, x: f64, y: f64) -> Result<f64> {
+        Ok(self(x, y))
+    }
+}
+
+impl<F: Fn(f64, f64, f64) -> f64> ThreeVariableFunction for F {
+    fn calculate(&self, x: f64, y: f64, z: f64) -> Result<f64> {
+        Ok(self(x, y, z))
+    }
+}
+
+pub struct Fallible<F>(pub F);
+
+impl<F: Fn(f64) -> std::result::Result<f64, E>, E: std::error::Error + Send + Sync + 'static>
+    OneVariableFunction for Fallible<F>
+{
+    fn calculate(&self, x: f64) -> Result<f64> {
+        (self.0)(x)
+            .map_err(errors::IntegrandError::from)
+            .context(errors::Integrand)
+    }
+}
+
+impl<
+        F: Fn(f64, f64) -> std::result::Result<f64, E>,
+        E: std::error::Error + Send + Sync + 'static,
+    > TwoVariableFunction for Fallible<F>
+{
+    fn calculate(&self, x: f64, y: f64) -> Result<f64> {
+        (self.0)(x, y)
+            .map_err(errors::IntegrandError::from)
+            .context(errors::Integrand)
+    }
+}
+
+impl<
+        F: Fn(f64, f64, f64) -> std::result::Result<f64, E>,
+        E: std::error::Error + Send + Sync + 'static,
+    > ThreeVariableFunction for Fallible<F>
+{
+    fn calculate(&self, x: f64, y: f64, z: f64) -> Result<f64> {
+        (self.0)(x, y, z)
+            .map_err(errors::IntegrandError::from)
+            .context(errors::Integrand)
+    }
+}
+
+pub type OuterVariablesFunction<'a> = Box<dyn Fn(&[f64]) -> f64 + 'a>;
@@ -1,10 +1,14 @@
 use super::{Bounds, CalculationResult, CalculationStep};
 use crate::errors::Result;
 
+/// Calculates one panel starting at `value`, `bounds` are the bounds of the whole dimension.
+/// Regular panels go to `add_common` unscaled, irregular and last panels go to `add_last`
+/// already multiplied by their own width.
 pub trait EquationOfOneVariable {
     fn calculate(&self, value: CalculationStep, bounds: Bounds) -> Result<CalculationResult>;
 }
 
+/// Calculates one panel of a double integral, see `EquationOfOneVariable`.
 pub trait EquationOfTwoVariable {
     fn calculate(
         &self,
@@ -15,11 +19,12 @@ pub trait EquationOfTwoVariable {
     ) -> Result<CalculationResult>;
 }
 
+/// Calculates one panel of a triple integral, see `EquationOfOneVariable`.
 pub trait EquationOfThreeVariable {
     fn calculate(
         &self,
         value1: CalculationStep,
-        bounds2: Bounds,
+        bounds1: Bounds,
         value2: CalculationStep,
         bounds2: Bounds,
         value3: CalculationStep,
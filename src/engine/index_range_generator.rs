@@ -0,0 +1,105 @@
+use fehler::throws;
+use snafu::ensure;
+
+use super::{
+    range_generator::{CalculationStep, RangeGenerator},
+    Bounds,
+};
+use crate::errors::{self, Error};
+
+const REMAINDER_EPS: f64 = 1e-9;
+// A tiny positive step would otherwise saturate the panel count and never finish.
+const MAX_PANELS: usize = 1 << 30;
+
+/// `RangeGenerator` computing every panel beginning from its index, so no error accumulates.
+pub struct IndexRangeGenerator {
+    begin: f64,
+    end: f64,
+    panel_width: f64,
+    panels: usize,
+    index: usize,
+}
+
+impl IndexRangeGenerator {
+    #[throws]
+    pub fn new(bounds: Bounds, panel_width: f64) -> Option<Self> {
+        let length = bounds.end - bounds.begin;
+        if length.abs() == 0. {
+            return None;
+        }
+
+        Some(Self {
+            begin: bounds.begin,
+            end: bounds.end,
+            panel_width: Self::equal_panel_width(length, panel_width),
+            panels: Self::count_panels(length, panel_width)?,
+            index: 0,
+        })
+    }
+
+    /// `panel_width` itself when it divides `length`, otherwise the width of the rounded up
+    /// number of equal panels.
+    pub fn equal_panel_width(length: f64, panel_width: f64) -> f64 {
+        match Self::ratio(length, panel_width) {
+            (_, true) => panel_width,
+            (panels, false) => length / panels,
+        }
+    }
+
+    #[throws]
+    pub fn count_panels(length: f64, panel_width: f64) -> usize {
+        let (panels, _) = Self::ratio(length, panel_width);
+        ensure!(
+            panels <= MAX_PANELS as f64,
+            errors::TooManyPanels {
+                panels,
+                max: MAX_PANELS
+            }
+        );
+
+        panels as usize
+    }
+
+    fn ratio(length: f64, panel_width: f64) -> (f64, bool) {
+        let ratio = length / panel_width;
+        let rounded = ratio.round();
+
+        if (ratio - rounded).abs() <= REMAINDER_EPS * rounded.max(1.) {
+            (rounded.max(1.), true)
+        } else {
+            (ratio.ceil(), false)
+        }
+    }
+
+    pub fn step(&self, index: usize) -> CalculationStep {
+        let value = if index == 0 {
+            self.begin
+        } else {
+            self.begin + index as f64 * self.panel_width
+        };
+
+        if index + 1 >= self.panels {
+            CalculationStep::Last(value)
+        } else {
+            CalculationStep::Common(value)
+        }
+    }
+}
+
+impl RangeGenerator for IndexRangeGenerator {
+    #[throws]
+    fn next(&mut self) -> CalculationStep {
+        ensure!(
+            self.index < self.panels,
+            errors::RangeGeneratorOutOfBounds {
+                step: self.begin + self.index as f64 * self.panel_width,
+                end: self.end
+            }
+        );
+
+        let result = self.step(self.index);
+
+        self.index += 1;
+        result
+    }
+}
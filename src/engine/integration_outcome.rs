@@ -0,0 +1,24 @@
+#[derive(Debug, Copy, Clone)]
+pub struct IntegrationOutcome {
+    pub value: f64,
+    pub error: f64,
+    pub evaluations: usize,
+    pub panels: usize,
+}
+
+impl IntegrationOutcome {
+    pub fn richardson(
+        value: f64,
+        coarse_value: f64,
+        convergence_order: i32,
+        evaluations: usize,
+        panels: usize,
+    ) -> Self {
+        Self {
+            value,
+            error: (value - coarse_value).abs() / (2f64.powi(convergence_order) - 1.),
+            evaluations,
+            panels,
+        }
+    }
+}
@@ -1,11 +1,14 @@
 use fehler::throws;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 use super::utils;
 use crate::{
     engine::{
         helper_equation_traits::EquationOfOneVariable,
         quadrature::{FinalizeCalculation, GetQuadratureRange},
-        CalculationResult,
+        range_generator::RangeGenerator,
+        CalculationResult, Resolution,
     },
     errors::Error,
 };
@@ -17,18 +20,31 @@ impl FirstIntegrator {
     pub fn integrate<E: EquationOfOneVariable, G: GetQuadratureRange + FinalizeCalculation>(
         a: f64,
         b: f64,
-        h: f64,
+        resolution: Resolution,
         equation: &E,
         quadrature: &G,
     ) -> f64 {
+        let result = Self::accumulate::<E, G>(a, b, resolution, equation)?;
+
+        quadrature.finalize(result)?
+    }
+
+    #[throws]
+    pub fn accumulate<E: EquationOfOneVariable, G: GetQuadratureRange>(
+        a: f64,
+        b: f64,
+        resolution: Resolution,
+        equation: &E,
+    ) -> CalculationResult {
         let borders_config = utils::BoundsConfigurator::configurate(a, b)?;
 
         let mut result = CalculationResult::new();
-        let mut range = if let Some(range) = G::get_range_generator(borders_config.bounds, h)? {
-            range
-        } else {
-            return result.common;
-        };
+        let mut range =
+            if let Some(range) = G::get_range_generator(borders_config.bounds, resolution)? {
+                range
+            } else {
+                return result;
+            };
 
         loop {
             let step = range.next()?;
@@ -40,6 +56,42 @@ impl FirstIntegrator {
             }
         }
 
-        quadrature.finalize(result)?
+        result
+    }
+
+    #[cfg(feature = "parallel")]
+    #[throws]
+    pub fn accumulate_parallel<E: EquationOfOneVariable + Sync, G: GetQuadratureRange>(
+        a: f64,
+        b: f64,
+        resolution: Resolution,
+        equation: &E,
+    ) -> CalculationResult {
+        let borders_config = utils::BoundsConfigurator::configurate(a, b)?;
+        let bounds = borders_config.bounds;
+
+        let mut steps = vec![];
+        if let Some(mut range) = G::get_range_generator(bounds, resolution)? {
+            loop {
+                let step = range.next()?;
+                steps.push(step);
+
+                if step.is_last() {
+                    break;
+                }
+            }
+        }
+
+        let panel_results = steps
+            .par_iter()
+            .map(|step| equation.calculate(*step, bounds))
+            .collect::<Vec<_>>();
+
+        let mut result = CalculationResult::new();
+        for panel_result in panel_results {
+            result += panel_result? * borders_config.direction_coeff;
+        }
+
+        result
     }
 }
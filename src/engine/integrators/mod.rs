@@ -7,4 +7,10 @@ pub use second_integrator::SecondIntegrator;
 mod third_integrator;
 pub use third_integrator::ThirdIntegrator;
 
+mod rule_integrator;
+pub use rule_integrator::RuleIntegrator;
+
+mod multiple_integrator;
+pub use multiple_integrator::MultipleIntegrator;
+
 mod utils;
@@ -0,0 +1,65 @@
+use fehler::throws;
+
+use super::RuleIntegrator;
+use crate::{
+    engine::{quadrature::QuadratureRule, DimensionBounds},
+    errors::Error,
+};
+
+pub struct MultipleIntegrator<'a, R: QuadratureRule, E: Fn(&[f64; N]) -> f64, const N: usize> {
+    rule: &'a R,
+    dimensions: &'a [DimensionBounds<'a>; N],
+    equation: &'a E,
+}
+
+impl<'a, R: QuadratureRule, E: Fn(&[f64; N]) -> f64, const N: usize>
+    MultipleIntegrator<'a, R, E, N>
+{
+    pub fn new(rule: &'a R, dimensions: &'a [DimensionBounds<'a>; N], equation: &'a E) -> Self {
+        Self {
+            rule,
+            dimensions,
+            equation,
+        }
+    }
+
+    #[throws]
+    pub fn integrate(&self) -> f64 {
+        self.integrate_dimension([0.; N], 0, (0., 0.))?
+    }
+
+    /// `outer_bounds` are the bounds of the slice of the previous dimension.
+    #[throws]
+    fn integrate_dimension(
+        &self,
+        point: [f64; N],
+        dimension: usize,
+        outer_bounds: (f64, f64),
+    ) -> f64 {
+        if dimension == N {
+            return (self.equation)(&point);
+        }
+
+        let bounds = &self.dimensions[dimension];
+        let a = (bounds.begin)(&point[..dimension]);
+        let b = (bounds.end)(&point[..dimension]);
+
+        let equation = |value| {
+            let mut point = point;
+            point[dimension] = value;
+            self.integrate_dimension(point, dimension + 1, (a, b))
+        };
+
+        if dimension == 0 {
+            RuleIntegrator::integrate_unbounded(a, b, self.rule, equation)?
+        } else {
+            RuleIntegrator::integrate_slice(
+                (point[dimension - 1], outer_bounds.0, outer_bounds.1),
+                a,
+                b,
+                self.rule,
+                equation,
+            )?
+        }
+    }
+}
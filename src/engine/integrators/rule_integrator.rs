@@ -0,0 +1,149 @@
+use fehler::throws;
+use snafu::ensure;
+
+use super::utils;
+#[cfg(feature = "parallel")]
+use crate::engine::quadrature::ParallelQuadratureRule;
+use crate::{
+    engine::{quadrature::QuadratureRule, Bounds},
+    errors::{self, Error, Result},
+};
+
+// Relative distance from an end of the enclosing slice still treated as that end.
+const EDGE_EPS: f64 = 1e-12;
+
+pub struct RuleIntegrator;
+
+impl RuleIntegrator {
+    #[throws]
+    pub fn integrate<R: QuadratureRule, E: Fn(f64) -> Result<f64>>(
+        a: f64,
+        b: f64,
+        rule: &R,
+        equation: E,
+    ) -> f64 {
+        ensure!(!a.is_infinite(), errors::InfiniteInnerBound { bound: a });
+        ensure!(!b.is_infinite(), errors::InfiniteInnerBound { bound: b });
+
+        let borders_config = utils::BoundsConfigurator::configurate(a, b)?;
+        if borders_config.bounds.begin == borders_config.bounds.end {
+            return 0.;
+        }
+
+        rule.integrate(equation, borders_config.bounds)? * borders_config.direction_coeff
+    }
+
+    /// Same as `integrate` for the slice at `outer` of the enclosing `[outer_begin, outer_end]`.
+    /// A NaN bound at an end of the enclosing slice is roundoff of a degenerate slice, like
+    /// `sqrt(1 - x^2 - y^2)` at `y = sqrt(1 - x^2)`, so the slice is empty.
+    #[throws]
+    pub fn integrate_slice<R: QuadratureRule, E: Fn(f64) -> Result<f64>>(
+        (outer, outer_begin, outer_end): (f64, f64, f64),
+        a: f64,
+        b: f64,
+        rule: &R,
+        equation: E,
+    ) -> f64 {
+        if (a.is_nan() || b.is_nan()) && Self::is_edge(outer, outer_begin, outer_end) {
+            return 0.;
+        }
+
+        Self::integrate(a, b, rule, equation)?
+    }
+
+    fn is_edge(value: f64, begin: f64, end: f64) -> bool {
+        let tolerance = EDGE_EPS * (end - begin).abs();
+
+        value == begin
+            || value == end
+            || (tolerance.is_finite()
+                && ((value - begin).abs() <= tolerance || (value - end).abs() <= tolerance))
+    }
+
+    /// Same as `integrate`, the outer dimension is calculated with `rule` on the rayon thread
+    /// pool, bounds must be finite.
+    #[cfg(feature = "parallel")]
+    #[throws]
+    pub fn integrate_parallel<R: ParallelQuadratureRule, E: Fn(f64) -> Result<f64> + Sync>(
+        a: f64,
+        b: f64,
+        rule: &R,
+        equation: E,
+    ) -> f64 {
+        let borders_config = utils::BoundsConfigurator::configurate(a, b)?;
+        if borders_config.bounds.begin == borders_config.bounds.end {
+            return 0.;
+        }
+
+        rule.integrate_parallel(equation, borders_config.bounds)? * borders_config.direction_coeff
+    }
+
+    /// Same as `integrate`, infinite bounds are mapped onto `[0, 1)`.
+    #[throws]
+    pub fn integrate_unbounded<R: QuadratureRule, E: Fn(f64) -> Result<f64>>(
+        a: f64,
+        b: f64,
+        rule: &R,
+        equation: E,
+    ) -> f64 {
+        if a.is_finite() && b.is_finite() {
+            return Self::integrate(a, b, rule, equation)?;
+        }
+
+        ensure!(!a.is_nan(), errors::NonFiniteBound { bound: a });
+        ensure!(!b.is_nan(), errors::NonFiniteBound { bound: b });
+        ensure!(a != b, errors::NonFiniteBound { bound: a });
+
+        let (begin, end, direction_coeff) = if a < b { (a, b, 1.) } else { (b, a, -1.) };
+        let result = if begin.is_finite() {
+            utils::SemiInfiniteTransform::new(begin, 1.).integrate(rule, &equation)?
+        } else if end.is_finite() {
+            utils::SemiInfiniteTransform::new(end, -1.).integrate(rule, &equation)?
+        } else {
+            utils::SemiInfiniteTransform::new(0., -1.).integrate(rule, &equation)?
+                + utils::SemiInfiniteTransform::new(0., 1.).integrate(rule, &equation)?
+        };
+
+        result * direction_coeff
+    }
+
+    /// Principal value of `∫ equation(x) / (x - pole) dx`, the pole term is integrated
+    /// analytically and the smooth remainder with `rule` on both sides of the pole.
+    #[throws]
+    pub fn principal_value<R: QuadratureRule, E: Fn(f64) -> Result<f64>>(
+        a: f64,
+        b: f64,
+        pole: f64,
+        rule: &R,
+        equation: E,
+    ) -> f64 {
+        ensure!(a.is_finite(), errors::NonFiniteBound { bound: a });
+        ensure!(b.is_finite(), errors::NonFiniteBound { bound: b });
+
+        let (begin, end, direction_coeff) = if a < b { (a, b, 1.) } else { (b, a, -1.) };
+        ensure!(
+            begin < pole && pole < end,
+            errors::InvalidPole {
+                pole,
+                begin: a,
+                end: b
+            }
+        );
+
+        let pole_value = equation(pole)?;
+        let remainder = |x: f64| {
+            if x == pole {
+                let delta = f64::EPSILON.cbrt() * pole.abs().max(1.);
+                return Ok((equation(pole + delta)? - equation(pole - delta)?) / (2. * delta));
+            }
+
+            Ok((equation(x)? - pole_value) / (x - pole))
+        };
+
+        let result = rule.integrate(remainder, Bounds::new(begin, pole)?)?
+            + rule.integrate(remainder, Bounds::new(pole, end)?)?
+            + pole_value * ((end - pole) / (pole - begin)).ln();
+
+        result * direction_coeff
+    }
+}
@@ -6,7 +6,8 @@ use crate::{
     engine::{
         helper_equation_traits::{EquationOfThreeVariable, EquationOfTwoVariable},
         quadrature::GetQuadratureRange,
-        Bounds, CalculationResult, CalculationStep,
+        range_generator::RangeGenerator,
+        Bounds, CalculationResult, CalculationStep, Resolution,
     },
     errors::Error,
 };
@@ -20,7 +21,7 @@ pub struct ThirdIntegrator<
 > {
     a_equation: F1,
     b_equation: F2,
-    h: f64,
+    resolution: Resolution,
     equation: &'a E,
     _p: PhantomData<G>,
 }
@@ -34,11 +35,11 @@ impl<
     > ThirdIntegrator<'a, G, E, F1, F2>
 {
     #[throws]
-    pub fn new(a_equation: F1, b_equation: F2, h: f64, equation: &'a E) -> Self {
+    pub fn new(a_equation: F1, b_equation: F2, resolution: Resolution, equation: &'a E) -> Self {
         Self {
             a_equation,
             b_equation,
-            h,
+            resolution,
             equation,
             _p: PhantomData,
         }
@@ -66,12 +67,12 @@ impl<
         let borders_config = integrator_utils::BoundsConfigurator::configurate(a, b)?;
 
         let mut result = CalculationResult::new();
-        let mut range = if let Some(range) = G::get_range_generator(borders_config.bounds, self.h)?
-        {
-            range
-        } else {
-            return result;
-        };
+        let mut range =
+            if let Some(range) = G::get_range_generator(borders_config.bounds, self.resolution)? {
+                range
+            } else {
+                return result;
+            };
 
         loop {
             let step = range.next()?;
@@ -10,9 +10,7 @@ pub struct BoundsConfigurator {
 impl BoundsConfigurator {
     #[throws]
     pub fn configurate(a: f64, b: f64) -> Self {
-        let begin = a.min(b);
-        let end = a.max(b);
-        let direction_coeff = if begin != a { -1. } else { 1. };
+        let (begin, end, direction_coeff) = if a <= b { (a, b, 1.) } else { (b, a, -1.) };
 
         Self {
             bounds: Bounds::new(begin, end)?,
@@ -1,2 +1,5 @@
 mod bounds_configurator;
 pub use bounds_configurator::BoundsConfigurator;
+
+mod semi_infinite_transform;
+pub use semi_infinite_transform::SemiInfiniteTransform;
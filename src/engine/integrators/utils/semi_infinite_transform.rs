@@ -0,0 +1,37 @@
+use fehler::throws;
+
+use crate::{
+    engine::{quadrature::QuadratureRule, Bounds},
+    errors::{Error, Result},
+};
+
+// Rules evaluating `t = 1` get the limit of the mapped integrand from this point instead,
+// `f(x) (1 + x)^2` tends to a constant when `f` decays like `1 / x^2`.
+const LAST_T: f64 = 1. - 1.4901161193847656e-8;
+
+/// Maps `t` in `[0, 1)` onto `[origin, ±inf)` with `x = origin + direction * t / (1 - t)`.
+pub struct SemiInfiniteTransform {
+    origin: f64,
+    direction: f64,
+}
+
+impl SemiInfiniteTransform {
+    pub fn new(origin: f64, direction: f64) -> Self {
+        Self { origin, direction }
+    }
+
+    #[throws]
+    pub fn integrate<R: QuadratureRule, E: Fn(f64) -> Result<f64>>(
+        &self,
+        rule: &R,
+        equation: &E,
+    ) -> f64 {
+        rule.integrate(
+            |t: f64| {
+                let u = 1. - t.min(LAST_T);
+                Ok(equation(self.origin + self.direction * (1. - u) / u)? / (u * u))
+            },
+            Bounds::new(0., 1.)?,
+        )?
+    }
+}
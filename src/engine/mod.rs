@@ -8,12 +8,28 @@ mod calculation_result;
 pub use calculation_result::CalculationResult;
 mod bounds;
 pub use bounds::Bounds;
+mod index_range_generator;
+pub use index_range_generator::IndexRangeGenerator;
+mod tolerance;
+pub use tolerance::Tolerance;
+mod step_validator;
+pub use step_validator::StepValidator;
+mod resolution;
+pub use resolution::Resolution;
+mod integration_outcome;
+pub use integration_outcome::IntegrationOutcome;
+mod non_finite_policy;
+pub use non_finite_policy::NonFinitePolicy;
+mod dimension_bounds;
+pub use dimension_bounds::DimensionBounds;
 pub mod function_types;
 
 use fehler::throws;
 
 use crate::errors::Error;
 
+/// Integrates `quadrature` panel by panel over `[first_integral_begin, first_integral_end]`,
+/// reversed bounds change the sign of the result.
 #[throws]
 pub fn calculate_single_integral<Q: quadrature::QuadratureSingleIntegral>(
     quadrature: Q,
@@ -23,7 +39,7 @@ pub fn calculate_single_integral<Q: quadrature::QuadratureSingleIntegral>(
     let result = integrators::Integrator::integrate::<Q, Q>(
         first_integral_begin,
         first_integral_end,
-        quadrature.get_step_size(),
+        quadrature.get_resolution(),
         &quadrature,
         &quadrature,
     )?;
@@ -32,36 +48,111 @@ pub fn calculate_single_integral<Q: quadrature::QuadratureSingleIntegral>(
 }
 
 #[throws]
-pub fn calculate_double_integral<
+fn accumulate_double_integral<
     Q: quadrature::QuadratureDoubleIntegral,
     F1: Fn(f64) -> f64,
     F2: Fn(f64) -> f64,
 >(
-    quadrature: Q,
+    quadrature: &Q,
     first_integral_begin: f64,
     first_integral_end: f64,
     second_integral_begin: F1,
     second_integral_end: F2,
-) -> f64 {
+) -> CalculationResult {
     let second_integrator = integrators::SecondIntegrator::<Q, Q, F1, F2>::new(
         second_integral_begin,
         second_integral_end,
-        quadrature.get_step_size().1,
-        &quadrature,
+        quadrature.get_resolution().1,
+        quadrature,
     )?;
 
     let result =
-        integrators::Integrator::integrate::<integrators::SecondIntegrator<Q, Q, F1, F2>, Q>(
+        integrators::Integrator::accumulate::<integrators::SecondIntegrator<Q, Q, F1, F2>, Q>(
             first_integral_begin,
             first_integral_end,
-            quadrature.get_step_size().0,
+            quadrature.get_resolution().0,
             &second_integrator,
-            &quadrature,
         )?;
 
     result
 }
 
+/// Integrates `quadrature` over the outer dimension and every inner slice
+/// `[second_integral_begin(x), second_integral_end(x)]`.
+#[throws]
+pub fn calculate_double_integral<
+    Q: quadrature::QuadratureDoubleIntegral,
+    F1: Fn(f64) -> f64,
+    F2: Fn(f64) -> f64,
+>(
+    quadrature: Q,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+) -> f64 {
+    let result = accumulate_double_integral(
+        &quadrature,
+        first_integral_begin,
+        first_integral_end,
+        second_integral_begin,
+        second_integral_end,
+    )?;
+
+    quadrature.finalize(result)?
+}
+
+#[allow(clippy::too_many_arguments)]
+#[throws]
+fn accumulate_triple_integral<
+    Q: quadrature::QuadratureTripleIntegral,
+    F1: Fn(f64) -> f64,
+    F2: Fn(f64) -> f64,
+    F3: Fn(f64, f64) -> f64,
+    F4: Fn(f64, f64) -> f64,
+>(
+    quadrature: &Q,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+    third_integral_begin: F3,
+    third_integral_end: F4,
+) -> CalculationResult {
+    let third_integrator = integrators::ThirdIntegrator::<Q, Q, F3, F4>::new(
+        third_integral_begin,
+        third_integral_end,
+        quadrature.get_resolution().2,
+        quadrature,
+    )?;
+
+    let second_integrator = integrators::SecondIntegrator::<
+        Q,
+        integrators::ThirdIntegrator<Q, Q, F3, F4>,
+        F1,
+        F2,
+    >::new(
+        second_integral_begin,
+        second_integral_end,
+        quadrature.get_resolution().1,
+        &third_integrator,
+    )?;
+
+    let result = integrators::Integrator::accumulate::<
+        integrators::SecondIntegrator<Q, integrators::ThirdIntegrator<Q, Q, F3, F4>, F1, F2>,
+        Q,
+    >(
+        first_integral_begin,
+        first_integral_end,
+        quadrature.get_resolution().0,
+        &second_integrator,
+    )?;
+
+    result
+}
+
+/// Integrates `quadrature` over the outer dimension and every inner slice, bounds of `z`
+/// depend on `x` and `y`.
 #[throws]
 pub fn calculate_triple_integral<
     Q: quadrature::QuadratureTripleIntegral,
@@ -77,11 +168,95 @@ pub fn calculate_triple_integral<
     second_integral_end: F2,
     third_integral_begin: F3,
     third_integral_end: F4,
+) -> f64 {
+    let result = accumulate_triple_integral(
+        &quadrature,
+        first_integral_begin,
+        first_integral_end,
+        second_integral_begin,
+        second_integral_end,
+        third_integral_begin,
+        third_integral_end,
+    )?;
+
+    quadrature.finalize(result)?
+}
+
+/// Same as `calculate_single_integral`, panels are calculated on the rayon thread pool and
+/// summed in order, so the result is bitwise identical to the sequential one.
+#[cfg(feature = "parallel")]
+#[throws]
+pub fn calculate_single_integral_parallel<Q: quadrature::QuadratureSingleIntegral + Sync>(
+    quadrature: Q,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+) -> f64 {
+    let result = integrators::Integrator::accumulate_parallel::<Q, Q>(
+        first_integral_begin,
+        first_integral_end,
+        quadrature.get_resolution(),
+        &quadrature,
+    )?;
+
+    quadrature.finalize(result)?
+}
+
+/// Same as `calculate_double_integral`, outer panels are calculated on the rayon thread pool.
+#[cfg(feature = "parallel")]
+#[throws]
+pub fn calculate_double_integral_parallel<
+    Q: quadrature::QuadratureDoubleIntegral + Sync,
+    F1: Fn(f64) -> f64 + Sync,
+    F2: Fn(f64) -> f64 + Sync,
+>(
+    quadrature: Q,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+) -> f64 {
+    let second_integrator = integrators::SecondIntegrator::<Q, Q, F1, F2>::new(
+        second_integral_begin,
+        second_integral_end,
+        quadrature.get_resolution().1,
+        &quadrature,
+    )?;
+
+    let result = integrators::Integrator::accumulate_parallel::<
+        integrators::SecondIntegrator<Q, Q, F1, F2>,
+        Q,
+    >(
+        first_integral_begin,
+        first_integral_end,
+        quadrature.get_resolution().0,
+        &second_integrator,
+    )?;
+
+    quadrature.finalize(result)?
+}
+
+/// Same as `calculate_triple_integral`, outer panels are calculated on the rayon thread pool.
+#[cfg(feature = "parallel")]
+#[throws]
+pub fn calculate_triple_integral_parallel<
+    Q: quadrature::QuadratureTripleIntegral + Sync,
+    F1: Fn(f64) -> f64 + Sync,
+    F2: Fn(f64) -> f64 + Sync,
+    F3: Fn(f64, f64) -> f64 + Sync,
+    F4: Fn(f64, f64) -> f64 + Sync,
+>(
+    quadrature: Q,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+    third_integral_begin: F3,
+    third_integral_end: F4,
 ) -> f64 {
     let third_integrator = integrators::ThirdIntegrator::<Q, Q, F3, F4>::new(
         third_integral_begin,
         third_integral_end,
-        quadrature.get_step_size().2,
+        quadrature.get_resolution().2,
         &quadrature,
     )?;
 
@@ -93,20 +268,293 @@ pub fn calculate_triple_integral<
     >::new(
         second_integral_begin,
         second_integral_end,
-        quadrature.get_step_size().1,
+        quadrature.get_resolution().1,
         &third_integrator,
     )?;
 
-    let result = integrators::Integrator::integrate::<
+    let result = integrators::Integrator::accumulate_parallel::<
         integrators::SecondIntegrator<Q, integrators::ThirdIntegrator<Q, Q, F3, F4>, F1, F2>,
         Q,
     >(
         first_integral_begin,
         first_integral_end,
-        quadrature.get_step_size().0,
+        quadrature.get_resolution().0,
         &second_integrator,
-        &quadrature,
     )?;
 
-    result
+    quadrature.finalize(result)?
+}
+
+/// Integrates `equation` with a `QuadratureRule`, infinite bounds are mapped onto `[0, 1)`.
+#[throws]
+pub fn calculate_single_integral_with_rule<
+    R: quadrature::QuadratureRule,
+    E: function_types::OneVariableFunction,
+>(
+    rule: R,
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+) -> f64 {
+    rule.reset_counts();
+
+    integrators::RuleIntegrator::integrate_unbounded(
+        first_integral_begin,
+        first_integral_end,
+        &rule,
+        |x| equation.calculate(x),
+    )?
+}
+
+/// Cauchy principal value of `∫ equation(x) / (x - pole) dx`, `pole` must lie strictly inside
+/// finite bounds.
+#[throws]
+pub fn calculate_principal_value_with_rule<
+    R: quadrature::QuadratureRule,
+    E: function_types::OneVariableFunction,
+>(
+    rule: R,
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    pole: f64,
+) -> f64 {
+    rule.reset_counts();
+
+    integrators::RuleIntegrator::principal_value(
+        first_integral_begin,
+        first_integral_end,
+        pole,
+        &rule,
+        |x| equation.calculate(x),
+    )?
+}
+
+/// Nests one `QuadratureRule` per dimension, inner bounds are evaluated at every outer node.
+/// Only the outer dimension may have infinite bounds, a NaN inner bound at an end of the
+/// enclosing slice is an empty slice.
+#[throws]
+pub fn calculate_double_integral_with_rules<
+    R1: quadrature::QuadratureRule,
+    R2: quadrature::QuadratureRule,
+    E: function_types::TwoVariableFunction,
+    F1: Fn(f64) -> f64,
+    F2: Fn(f64) -> f64,
+>(
+    first_rule: R1,
+    second_rule: R2,
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+) -> f64 {
+    first_rule.reset_counts();
+    second_rule.reset_counts();
+
+    integrators::RuleIntegrator::integrate_unbounded(
+        first_integral_begin,
+        first_integral_end,
+        &first_rule,
+        |x| {
+            integrators::RuleIntegrator::integrate_slice(
+                (x, first_integral_begin, first_integral_end),
+                second_integral_begin(x),
+                second_integral_end(x),
+                &second_rule,
+                |y| equation.calculate(x, y),
+            )
+        },
+    )?
+}
+
+/// Nests one `QuadratureRule` per dimension, inner bounds are evaluated at every outer node.
+/// Only the outer dimension may have infinite bounds, a NaN inner bound at an end of the
+/// enclosing slice is an empty slice.
+#[allow(clippy::too_many_arguments)]
+#[throws]
+pub fn calculate_triple_integral_with_rules<
+    R1: quadrature::QuadratureRule,
+    R2: quadrature::QuadratureRule,
+    R3: quadrature::QuadratureRule,
+    E: function_types::ThreeVariableFunction,
+    F1: Fn(f64) -> f64,
+    F2: Fn(f64) -> f64,
+    F3: Fn(f64, f64) -> f64,
+    F4: Fn(f64, f64) -> f64,
+>(
+    first_rule: R1,
+    second_rule: R2,
+    third_rule: R3,
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+    third_integral_begin: F3,
+    third_integral_end: F4,
+) -> f64 {
+    first_rule.reset_counts();
+    second_rule.reset_counts();
+    third_rule.reset_counts();
+
+    integrators::RuleIntegrator::integrate_unbounded(
+        first_integral_begin,
+        first_integral_end,
+        &first_rule,
+        |x| {
+            let (second_begin, second_end) = (second_integral_begin(x), second_integral_end(x));
+
+            integrators::RuleIntegrator::integrate_slice(
+                (x, first_integral_begin, first_integral_end),
+                second_begin,
+                second_end,
+                &second_rule,
+                |y| {
+                    integrators::RuleIntegrator::integrate_slice(
+                        (y, second_begin, second_end),
+                        third_integral_begin(x, y),
+                        third_integral_end(x, y),
+                        &third_rule,
+                        |z| equation.calculate(x, y, z),
+                    )
+                },
+            )
+        },
+    )?
+}
+
+/// Same as `calculate_single_integral_with_rule`, nodes are calculated on the rayon thread pool
+/// and summed in order, so the result is bitwise identical to the sequential one.
+/// Bounds must be finite.
+#[cfg(feature = "parallel")]
+#[throws]
+pub fn calculate_single_integral_with_rule_parallel<
+    R: quadrature::ParallelQuadratureRule,
+    E: function_types::OneVariableFunction + Sync,
+>(
+    rule: R,
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+) -> f64 {
+    rule.reset_counts();
+
+    integrators::RuleIntegrator::integrate_parallel(
+        first_integral_begin,
+        first_integral_end,
+        &rule,
+        |x| equation.calculate(x),
+    )?
+}
+
+/// Same as `calculate_double_integral_with_rules`, outer nodes are calculated on the rayon
+/// thread pool. Bounds must be finite.
+#[cfg(feature = "parallel")]
+#[throws]
+pub fn calculate_double_integral_with_rules_parallel<
+    R1: quadrature::ParallelQuadratureRule,
+    R2: quadrature::QuadratureRule + Sync,
+    E: function_types::TwoVariableFunction + Sync,
+    F1: Fn(f64) -> f64 + Sync,
+    F2: Fn(f64) -> f64 + Sync,
+>(
+    first_rule: R1,
+    second_rule: R2,
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+) -> f64 {
+    first_rule.reset_counts();
+    second_rule.reset_counts();
+
+    integrators::RuleIntegrator::integrate_parallel(
+        first_integral_begin,
+        first_integral_end,
+        &first_rule,
+        |x| {
+            integrators::RuleIntegrator::integrate_slice(
+                (x, first_integral_begin, first_integral_end),
+                second_integral_begin(x),
+                second_integral_end(x),
+                &second_rule,
+                |y| equation.calculate(x, y),
+            )
+        },
+    )?
+}
+
+/// Same as `calculate_triple_integral_with_rules`, outer nodes are calculated on the rayon
+/// thread pool. Bounds must be finite.
+#[cfg(feature = "parallel")]
+#[allow(clippy::too_many_arguments)]
+#[throws]
+pub fn calculate_triple_integral_with_rules_parallel<
+    R1: quadrature::ParallelQuadratureRule,
+    R2: quadrature::QuadratureRule + Sync,
+    R3: quadrature::QuadratureRule + Sync,
+    E: function_types::ThreeVariableFunction + Sync,
+    F1: Fn(f64) -> f64 + Sync,
+    F2: Fn(f64) -> f64 + Sync,
+    F3: Fn(f64, f64) -> f64 + Sync,
+    F4: Fn(f64, f64) -> f64 + Sync,
+>(
+    first_rule: R1,
+    second_rule: R2,
+    third_rule: R3,
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+    third_integral_begin: F3,
+    third_integral_end: F4,
+) -> f64 {
+    first_rule.reset_counts();
+    second_rule.reset_counts();
+    third_rule.reset_counts();
+
+    integrators::RuleIntegrator::integrate_parallel(
+        first_integral_begin,
+        first_integral_end,
+        &first_rule,
+        |x| {
+            let (second_begin, second_end) = (second_integral_begin(x), second_integral_end(x));
+
+            integrators::RuleIntegrator::integrate_slice(
+                (x, first_integral_begin, first_integral_end),
+                second_begin,
+                second_end,
+                &second_rule,
+                |y| {
+                    integrators::RuleIntegrator::integrate_slice(
+                        (y, second_begin, second_end),
+                        third_integral_begin(x, y),
+                        third_integral_end(x, y),
+                        &third_rule,
+                        |z| equation.calculate(x, y, z),
+                    )
+                },
+            )
+        },
+    )?
+}
+
+/// Nests `rule` over every dimension, bounds of dimension `i` depend on the first `i` variables.
+/// Only the first dimension may have infinite bounds.
+#[throws]
+pub fn calculate_multiple_integral_with_rule<
+    R: quadrature::QuadratureRule,
+    E: Fn(&[f64; N]) -> f64,
+    const N: usize,
+>(
+    rule: R,
+    equation: E,
+    dimensions: &[DimensionBounds; N],
+) -> f64 {
+    rule.reset_counts();
+
+    integrators::MultipleIntegrator::new(&rule, dimensions, &equation).integrate()?
 }
@@ -0,0 +1,71 @@
+use fehler::throws;
+use snafu::ensure;
+
+use crate::errors::{self, Error};
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub enum NonFinitePolicy {
+    #[default]
+    Propagate,
+    Error,
+    /// Drops the point and rescales the remaining weights of its panel to the full weight, so
+    /// the panel acts as if the dropped value were the weighted mean of the kept ones.
+    /// A panel without finite values returns `Error::NonFinitePanel`.
+    SkipPoint,
+    TreatAsZero,
+}
+
+impl NonFinitePolicy {
+    #[throws]
+    pub fn weighted_sum<W: Fn(usize) -> f64, P: Fn(usize) -> Vec<f64>>(
+        &self,
+        values: &[f64],
+        weight: W,
+        point: P,
+    ) -> f64 {
+        let mut sum = 0.;
+        let mut total_weight = 0.;
+        let mut kept_weight = 0.;
+
+        for (i, value) in values.iter().enumerate() {
+            let w = weight(i);
+            total_weight += w;
+
+            if value.is_finite() || *self == NonFinitePolicy::Propagate {
+                sum += w * value;
+                kept_weight += w;
+            } else if *self == NonFinitePolicy::Error {
+                errors::NonFiniteIntegrand { point: point(i) }.fail()?;
+            }
+        }
+
+        match self {
+            NonFinitePolicy::SkipPoint if kept_weight < total_weight => {
+                ensure!(kept_weight > 0., errors::NonFinitePanel { point: point(0) });
+                sum * total_weight / kept_weight
+            }
+            _ => sum,
+        }
+    }
+
+    /// Prepends `node` of an outer dimension to the point of an error of an inner one.
+    pub fn with_outer_node(node: f64, error: Error) -> Error {
+        match error {
+            Error::NonFiniteIntegrand {
+                mut point,
+                backtrace,
+            } => {
+                point.insert(0, node);
+                Error::NonFiniteIntegrand { point, backtrace }
+            }
+            Error::NonFinitePanel {
+                mut point,
+                backtrace,
+            } => {
+                point.insert(0, node);
+                Error::NonFinitePanel { point, backtrace }
+            }
+            error => error,
+        }
+    }
+}
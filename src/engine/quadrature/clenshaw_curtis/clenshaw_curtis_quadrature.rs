@@ -0,0 +1,108 @@
+use fehler::throws;
+use snafu::ensure;
+use std::{cell::OnceCell, f64::consts::PI};
+
+use crate::{
+    engine::{quadrature::QuadratureRule, Bounds, Tolerance},
+    errors::{self, Error, Result},
+};
+
+const MAX_LEVEL: usize = 12;
+// Estimates of the first levels are too coarse to be compared.
+const MIN_LEVEL: usize = 3;
+
+/// Clenshaw–Curtis quadrature on `2^level + 1` Chebyshev extrema `x_j = cos(jπ / 2^level)`.
+/// Nodes of a level are the even nodes of the next one, so every refinement reuses all
+/// previous values and the difference of two last levels is the error estimate.
+/// Weights of a level are computed on its first use.
+pub struct ClenshawCurtisQuadrature {
+    tolerance: Tolerance,
+    max_level: usize,
+    weights: Vec<OnceCell<Vec<f64>>>,
+}
+
+impl ClenshawCurtisQuadrature {
+    #[throws]
+    pub fn new(abs_tol: f64, rel_tol: f64, max_level: usize) -> Self {
+        ensure!(
+            (MIN_LEVEL..=MAX_LEVEL).contains(&max_level),
+            errors::InvalidLevel {
+                level: max_level,
+                min: MIN_LEVEL,
+                max: MAX_LEVEL
+            }
+        );
+
+        Self {
+            tolerance: Tolerance::new(abs_tol, rel_tol)?,
+            max_level,
+            weights: (0..=max_level).map(|_| OnceCell::new()).collect(),
+        }
+    }
+
+    fn weights(n: usize) -> Vec<f64> {
+        (0..=n)
+            .map(|j| {
+                let mut sum = 1.;
+                for k in 1..=n / 2 {
+                    let b = if 2 * k == n { 1. } else { 2. };
+                    let angle = (2 * k * j) as f64 * PI / n as f64;
+                    sum -= b * angle.cos() / (4. * (k * k) as f64 - 1.);
+                }
+
+                let c = if j == 0 || j == n { 1. } else { 2. };
+                c * sum / n as f64
+            })
+            .collect()
+    }
+
+    fn sum(&self, level: usize, values: &[f64], radius: f64) -> f64 {
+        radius
+            * self.weights[level]
+                .get_or_init(|| Self::weights(1 << level))
+                .iter()
+                .zip(values.iter())
+                .map(|(w, f)| w * f)
+                .sum::<f64>()
+    }
+}
+
+impl QuadratureRule for ClenshawCurtisQuadrature {
+    #[throws]
+    fn integrate<E: Fn(f64) -> Result<f64>>(&self, equation: E, bounds: Bounds) -> f64 {
+        let center = (bounds.begin + bounds.end) / 2.;
+        let radius = (bounds.end - bounds.begin) / 2.;
+
+        let mut values = vec![equation(bounds.end)?, equation(bounds.begin)?];
+        let mut value = self.sum(0, &values, radius);
+        let mut error = f64::INFINITY;
+
+        for level in 1..=self.max_level {
+            let n = 1 << level;
+            let mut refined = Vec::with_capacity(n + 1);
+
+            for (j, previous) in values.iter().enumerate() {
+                if j > 0 {
+                    let angle = (2 * j - 1) as f64 * PI / n as f64;
+                    refined.push(equation(center + radius * angle.cos())?);
+                }
+                refined.push(*previous);
+            }
+
+            let refined_value = self.sum(level, &refined, radius);
+            error = (refined_value - value).abs();
+            value = refined_value;
+            values = refined;
+
+            if level >= MIN_LEVEL && error <= self.tolerance.target(value) {
+                return value;
+            }
+        }
+
+        errors::LevelLimitReached {
+            limit: self.max_level,
+            error,
+        }
+        .fail()?
+    }
+}
@@ -0,0 +1,2 @@
+mod clenshaw_curtis_quadrature;
+pub use clenshaw_curtis_quadrature::ClenshawCurtisQuadrature;
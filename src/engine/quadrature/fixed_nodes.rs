@@ -0,0 +1,53 @@
+use fehler::throws;
+
+use crate::{
+    engine::function_types::{OneVariableFunction, ThreeVariableFunction, TwoVariableFunction},
+    errors::Error,
+};
+
+/// Nodes and weights of a rule over a fixed domain, the weight function is implicit.
+pub struct FixedNodes {
+    pub nodes: Vec<f64>,
+    pub weights: Vec<f64>,
+}
+
+impl FixedNodes {
+    #[throws]
+    pub fn integrate_single<E: OneVariableFunction>(&self, equation: &E) -> f64 {
+        let mut result = 0.;
+
+        for (x, w_x) in self.nodes.iter().zip(self.weights.iter()) {
+            result += w_x * equation.calculate(*x)?;
+        }
+
+        result
+    }
+
+    #[throws]
+    pub fn integrate_double<E: TwoVariableFunction>(&self, equation: &E) -> f64 {
+        let mut result = 0.;
+
+        for (x, w_x) in self.nodes.iter().zip(self.weights.iter()) {
+            for (y, w_y) in self.nodes.iter().zip(self.weights.iter()) {
+                result += w_x * w_y * equation.calculate(*x, *y)?;
+            }
+        }
+
+        result
+    }
+
+    #[throws]
+    pub fn integrate_triple<E: ThreeVariableFunction>(&self, equation: &E) -> f64 {
+        let mut result = 0.;
+
+        for (x, w_x) in self.nodes.iter().zip(self.weights.iter()) {
+            for (y, w_y) in self.nodes.iter().zip(self.weights.iter()) {
+                for (z, w_z) in self.nodes.iter().zip(self.weights.iter()) {
+                    result += w_x * w_y * w_z * equation.calculate(*x, *y, *z)?;
+                }
+            }
+        }
+
+        result
+    }
+}
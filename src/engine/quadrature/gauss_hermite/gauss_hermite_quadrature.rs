@@ -0,0 +1,108 @@
+use fehler::throws;
+use snafu::ensure;
+
+use crate::{
+    engine::{
+        function_types::{OneVariableFunction, ThreeVariableFunction, TwoVariableFunction},
+        quadrature::fixed_nodes::FixedNodes,
+    },
+    errors::{self, Error},
+};
+
+const MIN_POINTS: usize = 1;
+const MAX_POINTS: usize = 100;
+const EPS: f64 = 1e-14;
+const MAX_ITERATIONS: usize = 100;
+
+/// Gauss–Hermite rule, `∫ e^(-x²) f(x) dx` over `(-∞, ∞)` per dimension.
+pub struct GaussHermiteQuadrature {
+    rule: FixedNodes,
+}
+
+impl GaussHermiteQuadrature {
+    #[throws]
+    pub fn new(points: usize) -> Self {
+        ensure!(
+            (MIN_POINTS..=MAX_POINTS).contains(&points),
+            errors::InvalidPointCount {
+                points,
+                min: MIN_POINTS,
+                max: MAX_POINTS
+            }
+        );
+
+        let n = points as f64;
+        let mut nodes = vec![0.; points];
+        let mut weights = vec![0.; points];
+
+        let mut z = 0.;
+        for i in 0..points.div_ceil(2) {
+            z = match i {
+                0 => (2. * n + 1.).sqrt() - 1.85575 * (2. * n + 1.).powf(-0.16667),
+                1 => z - 1.14 * n.powf(0.426) / z,
+                2 => 1.86 * z - 0.86 * nodes[0],
+                3 => 1.91 * z - 0.91 * nodes[1],
+                _ => 2. * z - nodes[i - 2],
+            };
+
+            let mut derivative = 0.;
+            for _ in 0..MAX_ITERATIONS {
+                let (value, previous) = Self::hermite(points, z);
+                derivative = (2. * n).sqrt() * previous;
+
+                let z_previous = z;
+                z = z_previous - value / derivative;
+                if (z - z_previous).abs() <= EPS * z.abs().max(1.) {
+                    break;
+                }
+            }
+
+            nodes[i] = z;
+            nodes[points - 1 - i] = -z;
+            weights[i] = 2. / (derivative * derivative);
+            weights[points - 1 - i] = weights[i];
+        }
+
+        Self {
+            rule: FixedNodes { nodes, weights },
+        }
+    }
+
+    // Orthonormal Hermite polynomials, so the recurrence does not overflow for many points.
+    fn hermite(points: usize, z: f64) -> (f64, f64) {
+        let mut value = std::f64::consts::PI.powf(-0.25);
+        let mut previous = 0.;
+
+        for j in 0..points {
+            let j = j as f64;
+            let before_previous = previous;
+            previous = value;
+            value = z * (2. / (j + 1.)).sqrt() * previous - (j / (j + 1.)).sqrt() * before_previous;
+        }
+
+        (value, previous)
+    }
+
+    pub fn nodes(&self) -> &[f64] {
+        &self.rule.nodes
+    }
+
+    pub fn weights(&self) -> &[f64] {
+        &self.rule.weights
+    }
+
+    #[throws]
+    pub fn integrate_single<E: OneVariableFunction>(&self, equation: &E) -> f64 {
+        self.rule.integrate_single(equation)?
+    }
+
+    #[throws]
+    pub fn integrate_double<E: TwoVariableFunction>(&self, equation: &E) -> f64 {
+        self.rule.integrate_double(equation)?
+    }
+
+    #[throws]
+    pub fn integrate_triple<E: ThreeVariableFunction>(&self, equation: &E) -> f64 {
+        self.rule.integrate_triple(equation)?
+    }
+}
@@ -0,0 +1,2 @@
+mod gauss_hermite_quadrature;
+pub use gauss_hermite_quadrature::GaussHermiteQuadrature;
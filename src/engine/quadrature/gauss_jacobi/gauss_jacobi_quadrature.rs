@@ -0,0 +1,171 @@
+use fehler::throws;
+use snafu::ensure;
+
+use crate::{
+    engine::{
+        quadrature::{special_functions, QuadratureRule},
+        Bounds,
+    },
+    errors::{self, Error, Result},
+};
+
+const MIN_POINTS: usize = 1;
+const MAX_POINTS: usize = 100;
+const EPS: f64 = 1e-14;
+const MAX_ITERATIONS: usize = 100;
+
+/// Gauss–Jacobi rule, `∫ (end - x)^α (x - begin)^β f(x) dx` over `bounds` mapped from
+/// `(1 - t)^α (1 + t)^β` on `[-1, 1]`. Nodes are never placed on the bounds.
+#[derive(Clone)]
+pub struct GaussJacobiQuadrature {
+    nodes: Vec<f64>,
+    weights: Vec<f64>,
+    alpha: f64,
+    beta: f64,
+}
+
+impl GaussJacobiQuadrature {
+    #[throws]
+    pub fn new(points: usize, alpha: f64, beta: f64) -> Self {
+        ensure!(
+            (MIN_POINTS..=MAX_POINTS).contains(&points),
+            errors::InvalidPointCount {
+                points,
+                min: MIN_POINTS,
+                max: MAX_POINTS
+            }
+        );
+        ensure!(
+            alpha > -1. && alpha.is_finite(),
+            errors::InvalidWeightExponent { exponent: alpha }
+        );
+        ensure!(
+            beta > -1. && beta.is_finite(),
+            errors::InvalidWeightExponent { exponent: beta }
+        );
+
+        let n = points as f64;
+        let alpha_beta = alpha + beta;
+        let mut nodes = vec![0.; points];
+        let mut weights = vec![0.; points];
+        let norm = (special_functions::ln_gamma(alpha + n) + special_functions::ln_gamma(beta + n)
+            - special_functions::ln_gamma(n + 1.)
+            - special_functions::ln_gamma(n + alpha_beta + 1.))
+        .exp()
+            * 2f64.powf(alpha_beta);
+
+        let mut z = 0.;
+        for i in 0..points {
+            z = Self::initial_guess(i, points, alpha, beta, z, &nodes);
+
+            let mut derivative = 0.;
+            let mut previous = 0.;
+            let mut temp = 0.;
+            for _ in 0..MAX_ITERATIONS {
+                let (value, jacobi_previous, jacobi_temp) = Self::jacobi(points, alpha, beta, z);
+                previous = jacobi_previous;
+                temp = jacobi_temp;
+                derivative = (n * (alpha - beta - temp * z) * value
+                    + 2. * (n + alpha) * (n + beta) * previous)
+                    / (temp * (1. - z * z));
+
+                let z_previous = z;
+                z = z_previous - value / derivative;
+                if (z - z_previous).abs() <= EPS {
+                    break;
+                }
+            }
+
+            nodes[i] = z;
+            weights[i] = norm * temp / (derivative * previous);
+        }
+
+        Self {
+            nodes,
+            weights,
+            alpha,
+            beta,
+        }
+    }
+
+    // Empirical starting points for the Newton iteration, largest node first.
+    #[allow(clippy::approx_constant)]
+    fn initial_guess(i: usize, points: usize, alpha: f64, beta: f64, z: f64, nodes: &[f64]) -> f64 {
+        let n = points as f64;
+
+        if i == 0 {
+            let an = alpha / n;
+            let bn = beta / n;
+            let r1 = (1. + alpha) * (2.78 / (4. + n * n) + 0.768 * an / n);
+            let r2 = 1. + 1.48 * an + 0.96 * bn + 0.452 * an * an + 0.83 * an * bn;
+            1. - r1 / r2
+        } else if i == 1 {
+            let r1 = (4.1 + alpha) / ((1. + alpha) * (1. + 0.156 * alpha));
+            let r2 = 1. + 0.06 * (n - 8.) * (1. + 0.12 * alpha) / n;
+            let r3 = 1. + 0.012 * beta * (1. + 0.25 * alpha.abs()) / n;
+            z - (1. - z) * r1 * r2 * r3
+        } else if i == 2 {
+            let r1 = (1.67 + 0.28 * alpha) / (1. + 0.37 * alpha);
+            let r2 = 1. + 0.22 * (n - 8.) / n;
+            let r3 = 1. + 8. * beta / ((6.28 + beta) * n * n);
+            z - (nodes[0] - z) * r1 * r2 * r3
+        } else if i == points - 2 {
+            let r1 = (1. + 0.235 * beta) / (0.766 + 0.119 * beta);
+            let r2 = 1. / (1. + 0.639 * (n - 4.) / (1. + 0.71 * (n - 4.)));
+            let r3 = 1. / (1. + 20. * alpha / ((7.5 + alpha) * n * n));
+            z + (z - nodes[points - 4]) * r1 * r2 * r3
+        } else if i == points - 1 {
+            let r1 = (1. + 0.37 * beta) / (1.67 + 0.28 * beta);
+            let r2 = 1. / (1. + 0.22 * (n - 8.) / n);
+            let r3 = 1. / (1. + 8. * alpha / ((6.28 + alpha) * n * n));
+            z + (z - nodes[points - 3]) * r1 * r2 * r3
+        } else {
+            3. * nodes[i - 1] - 3. * nodes[i - 2] + nodes[i - 3]
+        }
+    }
+
+    // Returns `P_n`, `P_(n-1)` and `2n + α + β`.
+    fn jacobi(points: usize, alpha: f64, beta: f64, z: f64) -> (f64, f64, f64) {
+        let alpha_beta = alpha + beta;
+        let mut temp = 2. + alpha_beta;
+        let mut value = (alpha - beta + temp * z) / 2.;
+        let mut previous = 1.;
+
+        for j in 2..=points {
+            let j = j as f64;
+            let before_previous = previous;
+            previous = value;
+            temp = 2. * j + alpha_beta;
+
+            let a = 2. * j * (j + alpha_beta) * (temp - 2.);
+            let b = (temp - 1.) * (alpha * alpha - beta * beta + temp * (temp - 2.) * z);
+            let c = 2. * (j - 1. + alpha) * (j - 1. + beta) * temp;
+            value = (b * previous - c * before_previous) / a;
+        }
+
+        (value, previous, temp)
+    }
+
+    pub fn nodes(&self) -> &[f64] {
+        &self.nodes
+    }
+
+    pub fn weights(&self) -> &[f64] {
+        &self.weights
+    }
+}
+
+impl QuadratureRule for GaussJacobiQuadrature {
+    #[throws]
+    fn integrate<E: Fn(f64) -> Result<f64>>(&self, equation: E, bounds: Bounds) -> f64 {
+        let center = (bounds.begin + bounds.end) / 2.;
+        let radius = (bounds.end - bounds.begin) / 2.;
+
+        let mut result = 0.;
+        for (t, w) in self.nodes.iter().zip(self.weights.iter()) {
+            result += w * equation(center + radius * t)?;
+        }
+
+        result * radius.powf(1. + self.alpha + self.beta)
+    }
+}
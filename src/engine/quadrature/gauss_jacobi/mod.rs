@@ -0,0 +1,2 @@
+mod gauss_jacobi_quadrature;
+pub use gauss_jacobi_quadrature::GaussJacobiQuadrature;
@@ -0,0 +1,104 @@
+use fehler::throws;
+use snafu::ensure;
+use std::collections::BinaryHeap;
+
+use super::gauss_kronrod_rule::GaussKronrodRule;
+use crate::{
+    engine::{quadrature::QuadratureRule, Bounds, Tolerance},
+    errors::{self, Error, Result},
+};
+
+struct Subinterval {
+    bounds: Bounds,
+    value: f64,
+    error: f64,
+}
+
+impl PartialEq for Subinterval {
+    fn eq(&self, other: &Self) -> bool {
+        self.error.total_cmp(&other.error).is_eq()
+    }
+}
+
+impl Eq for Subinterval {}
+
+impl PartialOrd for Subinterval {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Subinterval {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.error.total_cmp(&other.error)
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct GaussKronrodQuadrature {
+    rule: GaussKronrodRule,
+    tolerance: Tolerance,
+    limit: usize,
+}
+
+impl GaussKronrodQuadrature {
+    #[throws]
+    pub fn new(rule: GaussKronrodRule, abs_tol: f64, rel_tol: f64, limit: usize) -> Self {
+        ensure!(limit > 0, errors::InvalidSubdivisionLimit { limit });
+
+        Self {
+            rule,
+            tolerance: Tolerance::new(abs_tol, rel_tol)?,
+            limit,
+        }
+    }
+
+    #[throws]
+    fn subinterval<E: Fn(f64) -> Result<f64>>(&self, equation: &E, bounds: Bounds) -> Subinterval {
+        let estimate = self.rule.estimate(equation, bounds)?;
+
+        Subinterval {
+            bounds,
+            value: estimate.value,
+            error: estimate.error,
+        }
+    }
+}
+
+impl QuadratureRule for GaussKronrodQuadrature {
+    #[throws]
+    fn integrate<E: Fn(f64) -> Result<f64>>(&self, equation: E, bounds: Bounds) -> f64 {
+        let whole = self.subinterval(&equation, bounds)?;
+        let mut value = whole.value;
+        let mut error = whole.error;
+
+        let mut subintervals = BinaryHeap::new();
+        subintervals.push(whole);
+
+        while error > self.tolerance.target(value) {
+            ensure!(
+                subintervals.len() < self.limit && error.is_finite(),
+                errors::SubdivisionLimitReached {
+                    limit: self.limit,
+                    error
+                }
+            );
+
+            let worst = subintervals.pop().unwrap();
+            let middle = (worst.bounds.begin + worst.bounds.end) / 2.;
+            let left = self.subinterval(&equation, Bounds::new(worst.bounds.begin, middle)?)?;
+            let right = self.subinterval(&equation, Bounds::new(middle, worst.bounds.end)?)?;
+
+            value += left.value + right.value - worst.value;
+            error += left.error + right.error - worst.error;
+
+            subintervals.push(left);
+            subintervals.push(right);
+        }
+
+        subintervals
+            .iter()
+            .map(|subinterval| subinterval.value)
+            .sum()
+    }
+}
@@ -0,0 +1,146 @@
+use fehler::throws;
+
+use crate::{
+    engine::Bounds,
+    errors::{Error, Result},
+};
+
+const G7K15_XGK: [f64; 8] = [
+    0.9914553711208126,
+    0.9491079123427585,
+    0.8648644233597691,
+    0.7415311855993945,
+    0.5860872354676911,
+    0.4058451513773972,
+    0.20778495500789848,
+    0.,
+];
+const G7K15_WGK: [f64; 8] = [
+    0.022935322010529224,
+    0.06309209262997856,
+    0.10479001032225019,
+    0.14065325971552592,
+    0.1690047266392679,
+    0.19035057806478542,
+    0.20443294007529889,
+    0.20948214108472782,
+];
+const G7K15_WG: [f64; 4] = [
+    0.1294849661688697,
+    0.27970539148927664,
+    0.3818300505051189,
+    0.4179591836734694,
+];
+
+const G10K21_XGK: [f64; 11] = [
+    0.9956571630258081,
+    0.9739065285171717,
+    0.9301574913557082,
+    0.8650633666889845,
+    0.7808177265864169,
+    0.6794095682990244,
+    0.5627571346686047,
+    0.4333953941292472,
+    0.2943928627014602,
+    0.14887433898163122,
+    0.,
+];
+const G10K21_WGK: [f64; 11] = [
+    0.011694638867371874,
+    0.032558162307964725,
+    0.054755896574351995,
+    0.07503967481091996,
+    0.0931254545836976,
+    0.10938715880229764,
+    0.12349197626206584,
+    0.13470921731147334,
+    0.14277593857706009,
+    0.14773910490133849,
+    0.1494455540029169,
+];
+const G10K21_WG: [f64; 5] = [
+    0.06667134430868814,
+    0.1494513491505806,
+    0.21908636251598204,
+    0.26926671930999635,
+    0.29552422471475287,
+];
+
+#[derive(Debug, Copy, Clone)]
+pub enum GaussKronrodRule {
+    G7K15,
+    G10K21,
+}
+
+pub struct GaussKronrodEstimate {
+    pub value: f64,
+    pub error: f64,
+}
+
+impl GaussKronrodRule {
+    fn nodes(&self) -> (&'static [f64], &'static [f64], &'static [f64]) {
+        match self {
+            GaussKronrodRule::G7K15 => (&G7K15_XGK, &G7K15_WGK, &G7K15_WG),
+            GaussKronrodRule::G10K21 => (&G10K21_XGK, &G10K21_WGK, &G10K21_WG),
+        }
+    }
+
+    #[throws]
+    pub fn estimate<E: Fn(f64) -> Result<f64>>(
+        &self,
+        equation: &E,
+        bounds: Bounds,
+    ) -> GaussKronrodEstimate {
+        let (xgk, wgk, wg) = self.nodes();
+        let center_index = xgk.len() - 1;
+        let has_gauss_center = wg.len() > center_index / 2;
+
+        let center = (bounds.begin + bounds.end) / 2.;
+        let half_width = (bounds.end - bounds.begin) / 2.;
+
+        let f_center = equation(center)?;
+        let mut result_kronrod = wgk[center_index] * f_center;
+        let mut result_gauss = if has_gauss_center {
+            wg[wg.len() - 1] * f_center
+        } else {
+            0.
+        };
+        let mut result_abs = result_kronrod.abs();
+
+        let mut f_pairs = [(0., 0.); G10K21_XGK.len() - 1];
+        for (j, x) in xgk.iter().take(center_index).enumerate() {
+            let f1 = equation(center - half_width * x)?;
+            let f2 = equation(center + half_width * x)?;
+
+            result_kronrod += wgk[j] * (f1 + f2);
+            result_abs += wgk[j] * (f1.abs() + f2.abs());
+            if j % 2 == 1 {
+                result_gauss += wg[j / 2] * (f1 + f2);
+            }
+
+            f_pairs[j] = (f1, f2);
+        }
+
+        let mean = result_kronrod / 2.;
+        let mut result_asc = wgk[center_index] * (f_center - mean).abs();
+        for (j, (f1, f2)) in f_pairs.iter().take(center_index).enumerate() {
+            result_asc += wgk[j] * ((f1 - mean).abs() + (f2 - mean).abs());
+        }
+
+        result_abs *= half_width.abs();
+        result_asc *= half_width.abs();
+
+        let mut error = ((result_kronrod - result_gauss) * half_width).abs();
+        if result_asc != 0. && error != 0. {
+            error = result_asc * (200. * error / result_asc).powf(1.5).min(1.);
+        }
+        if result_abs > f64::MIN_POSITIVE / (50. * f64::EPSILON) {
+            error = error.max(50. * f64::EPSILON * result_abs);
+        }
+
+        GaussKronrodEstimate {
+            value: result_kronrod * half_width,
+            error,
+        }
+    }
+}
@@ -0,0 +1,4 @@
+mod gauss_kronrod_quadrature;
+pub use gauss_kronrod_quadrature::GaussKronrodQuadrature;
+mod gauss_kronrod_rule;
+pub use gauss_kronrod_rule::GaussKronrodRule;
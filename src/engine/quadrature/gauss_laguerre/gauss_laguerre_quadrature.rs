@@ -0,0 +1,116 @@
+use fehler::throws;
+use snafu::ensure;
+
+use crate::{
+    engine::{
+        function_types::{OneVariableFunction, ThreeVariableFunction, TwoVariableFunction},
+        quadrature::{fixed_nodes::FixedNodes, special_functions},
+    },
+    errors::{self, Error},
+};
+
+const MIN_POINTS: usize = 1;
+const MAX_POINTS: usize = 100;
+const EPS: f64 = 1e-14;
+const MAX_ITERATIONS: usize = 100;
+
+/// Generalized Gauss–Laguerre rule, `∫ x^α e^(-x) f(x) dx` over `[0, ∞)` per dimension.
+pub struct GaussLaguerreQuadrature {
+    rule: FixedNodes,
+}
+
+impl GaussLaguerreQuadrature {
+    #[throws]
+    pub fn new(points: usize, alpha: f64) -> Self {
+        ensure!(
+            (MIN_POINTS..=MAX_POINTS).contains(&points),
+            errors::InvalidPointCount {
+                points,
+                min: MIN_POINTS,
+                max: MAX_POINTS
+            }
+        );
+        ensure!(
+            alpha > -1. && alpha.is_finite(),
+            errors::InvalidWeightExponent { exponent: alpha }
+        );
+
+        let n = points as f64;
+        let mut nodes = vec![0.; points];
+        let mut weights = vec![0.; points];
+        let norm = (special_functions::ln_gamma(n + alpha) - special_functions::ln_gamma(n)).exp();
+
+        let mut z = 0.;
+        for i in 0..points {
+            z = match i {
+                0 => (1. + alpha) * (3. + 0.92 * alpha) / (1. + 2.4 * n + 1.8 * alpha),
+                1 => z + (15. + 6.25 * alpha) / (1. + 0.9 * alpha + 2.5 * n),
+                _ => {
+                    let ai = (i - 1) as f64;
+                    z + ((1. + 2.55 * ai) / (1.9 * ai) + 1.26 * ai * alpha / (1. + 3.5 * ai))
+                        * (z - nodes[i - 2])
+                        / (1. + 0.3 * alpha)
+                }
+            };
+
+            let mut derivative = 0.;
+            let mut previous = 0.;
+            for _ in 0..MAX_ITERATIONS {
+                let (value, laguerre_previous) = Self::laguerre(points, alpha, z);
+                previous = laguerre_previous;
+                derivative = (n * value - (n + alpha) * previous) / z;
+
+                let z_previous = z;
+                z = z_previous - value / derivative;
+                if (z - z_previous).abs() <= EPS * z.abs().max(1.) {
+                    break;
+                }
+            }
+
+            nodes[i] = z;
+            weights[i] = -norm / (derivative * n * previous);
+        }
+
+        Self {
+            rule: FixedNodes { nodes, weights },
+        }
+    }
+
+    fn laguerre(points: usize, alpha: f64, z: f64) -> (f64, f64) {
+        let mut value = 1.;
+        let mut previous = 0.;
+
+        for j in 0..points {
+            let j = j as f64;
+            let before_previous = previous;
+            previous = value;
+            value =
+                ((2. * j + 1. + alpha - z) * previous - (j + alpha) * before_previous) / (j + 1.);
+        }
+
+        (value, previous)
+    }
+
+    pub fn nodes(&self) -> &[f64] {
+        &self.rule.nodes
+    }
+
+    pub fn weights(&self) -> &[f64] {
+        &self.rule.weights
+    }
+
+    #[throws]
+    pub fn integrate_single<E: OneVariableFunction>(&self, equation: &E) -> f64 {
+        self.rule.integrate_single(equation)?
+    }
+
+    #[throws]
+    pub fn integrate_double<E: TwoVariableFunction>(&self, equation: &E) -> f64 {
+        self.rule.integrate_double(equation)?
+    }
+
+    #[throws]
+    pub fn integrate_triple<E: ThreeVariableFunction>(&self, equation: &E) -> f64 {
+        self.rule.integrate_triple(equation)?
+    }
+}
@@ -0,0 +1,2 @@
+mod gauss_laguerre_quadrature;
+pub use gauss_laguerre_quadrature::GaussLaguerreQuadrature;
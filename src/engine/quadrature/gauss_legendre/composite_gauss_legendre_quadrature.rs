@@ -0,0 +1,53 @@
+use fehler::throws;
+
+use super::utils as gauss_legendre_utils;
+use crate::{
+    engine::{
+        quadrature::QuadratureRule, range_generator::RangeGenerator, Bounds, IndexRangeGenerator,
+        Resolution,
+    },
+    errors::{Error, Result},
+};
+
+pub struct CompositeGaussLegendreQuadrature {
+    nodes: gauss_legendre_utils::GaussLegendreNodes,
+    h: Resolution,
+}
+
+impl CompositeGaussLegendreQuadrature {
+    #[throws]
+    pub fn new(h: Resolution, points: usize) -> Self {
+        Self {
+            nodes: gauss_legendre_utils::GaussLegendreNodes::new(points)?,
+            h: h.validate()?,
+        }
+    }
+}
+
+impl QuadratureRule for CompositeGaussLegendreQuadrature {
+    #[throws]
+    fn integrate<E: Fn(f64) -> Result<f64>>(&self, equation: E, bounds: Bounds) -> f64 {
+        let panel_width = self.h.panel_width(bounds, 1.);
+        let mut range = if let Some(range) = IndexRangeGenerator::new(bounds, panel_width)? {
+            range
+        } else {
+            return 0.;
+        };
+
+        let mut result = 0.;
+        loop {
+            let step = range.next()?;
+            let x = gauss_legendre_utils::GaussLegendrePoints::generate(step, bounds, panel_width);
+
+            for (t, w) in self.nodes.nodes.iter().zip(self.nodes.weights.iter()) {
+                result += x.half_width * w * equation(x.node(*t))?;
+            }
+
+            if step.is_last() {
+                break;
+            }
+        }
+
+        result
+    }
+}
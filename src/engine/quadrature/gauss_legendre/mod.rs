@@ -0,0 +1,3 @@
+mod composite_gauss_legendre_quadrature;
+pub use composite_gauss_legendre_quadrature::CompositeGaussLegendreQuadrature;
+mod utils;
@@ -0,0 +1,69 @@
+use fehler::throws;
+use snafu::ensure;
+
+use crate::errors::{self, Error};
+
+const MIN_POINTS: usize = 2;
+const MAX_POINTS: usize = 64;
+const EPS: f64 = 1e-15;
+const MAX_ITERATIONS: usize = 100;
+
+pub struct GaussLegendreNodes {
+    pub nodes: Vec<f64>,
+    pub weights: Vec<f64>,
+}
+
+impl GaussLegendreNodes {
+    #[throws]
+    pub fn new(points: usize) -> Self {
+        ensure!(
+            (MIN_POINTS..=MAX_POINTS).contains(&points),
+            errors::InvalidPointCount {
+                points,
+                min: MIN_POINTS,
+                max: MAX_POINTS
+            }
+        );
+
+        let n = points as f64;
+        let mut nodes = vec![0.; points];
+        let mut weights = vec![0.; points];
+
+        for i in 0..points.div_ceil(2) {
+            let mut z = (std::f64::consts::PI * (i as f64 + 0.75) / (n + 0.5)).cos();
+            let mut derivative = 0.;
+
+            for _ in 0..MAX_ITERATIONS {
+                let (value, previous) = Self::legendre(points, z);
+                derivative = n * (z * value - previous) / (z * z - 1.);
+
+                let z_previous = z;
+                z = z_previous - value / derivative;
+                if (z - z_previous).abs() <= EPS {
+                    break;
+                }
+            }
+
+            nodes[i] = -z;
+            nodes[points - 1 - i] = z;
+            weights[i] = 2. / ((1. - z * z) * derivative * derivative);
+            weights[points - 1 - i] = weights[i];
+        }
+
+        Self { nodes, weights }
+    }
+
+    fn legendre(points: usize, z: f64) -> (f64, f64) {
+        let mut value = 1.;
+        let mut previous = 0.;
+
+        for j in 1..=points {
+            let j = j as f64;
+            let before_previous = previous;
+            previous = value;
+            value = ((2. * j - 1.) * z * previous - (j - 1.) * before_previous) / j;
+        }
+
+        (value, previous)
+    }
+}
@@ -0,0 +1,26 @@
+use crate::engine::{Bounds, CalculationStep};
+
+pub struct GaussLegendrePoints {
+    pub center: f64,
+    pub half_width: f64,
+}
+
+impl GaussLegendrePoints {
+    pub fn generate(step: CalculationStep, bounds: Bounds, panel_width: f64) -> Self {
+        let begin = *step;
+        let end = if step.is_last() {
+            bounds.end
+        } else {
+            begin + panel_width
+        };
+
+        Self {
+            center: (begin + end) / 2.,
+            half_width: (end - begin) / 2.,
+        }
+    }
+
+    pub fn node(&self, t: f64) -> f64 {
+        self.center + self.half_width * t
+    }
+}
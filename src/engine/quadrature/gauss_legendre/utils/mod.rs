@@ -0,0 +1,4 @@
+mod gauss_legendre_nodes;
+pub use gauss_legendre_nodes::GaussLegendreNodes;
+mod gauss_legendre_points;
+pub use gauss_legendre_points::GaussLegendrePoints;
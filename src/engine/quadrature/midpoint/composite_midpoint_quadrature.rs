@@ -0,0 +1,54 @@
+use fehler::throws;
+
+use super::utils as midpoint_utils;
+use crate::{
+    engine::{
+        quadrature::{GetConvergenceOrder, QuadratureRule},
+        range_generator::RangeGenerator,
+        Bounds, IndexRangeGenerator, Resolution,
+    },
+    errors::{Error, Result},
+};
+
+pub struct CompositeMidpointQuadrature {
+    h: Resolution,
+}
+
+impl CompositeMidpointQuadrature {
+    #[throws]
+    pub fn new(h: Resolution) -> Self {
+        Self { h: h.validate()? }
+    }
+}
+
+impl QuadratureRule for CompositeMidpointQuadrature {
+    #[throws]
+    fn integrate<E: Fn(f64) -> Result<f64>>(&self, equation: E, bounds: Bounds) -> f64 {
+        let panel_width = self.h.panel_width(bounds, 1.);
+        let mut range = if let Some(range) = IndexRangeGenerator::new(bounds, panel_width)? {
+            range
+        } else {
+            return 0.;
+        };
+
+        let mut result = 0.;
+        loop {
+            let step = range.next()?;
+            let x = midpoint_utils::MidpointPoints::generate(step, bounds, panel_width);
+
+            result += x.h * equation(x.v)?;
+
+            if step.is_last() {
+                break;
+            }
+        }
+
+        result
+    }
+}
+
+impl GetConvergenceOrder for CompositeMidpointQuadrature {
+    fn get_convergence_order(&self) -> i32 {
+        2
+    }
+}
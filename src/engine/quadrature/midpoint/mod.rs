@@ -0,0 +1,3 @@
+mod composite_midpoint_quadrature;
+pub use composite_midpoint_quadrature::CompositeMidpointQuadrature;
+mod utils;
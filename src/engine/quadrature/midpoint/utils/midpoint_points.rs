@@ -0,0 +1,23 @@
+use crate::engine::{Bounds, CalculationStep};
+
+pub struct MidpointPoints {
+    pub v: f64,
+    pub h: f64,
+}
+
+impl MidpointPoints {
+    /// Panel beginning at `step`, the last one ends exactly on the bound.
+    pub fn generate(step: CalculationStep, bounds: Bounds, panel_width: f64) -> Self {
+        let begin = *step;
+        let end = if step.is_last() {
+            bounds.end
+        } else {
+            begin + panel_width
+        };
+
+        Self {
+            v: (begin + end) / 2.,
+            h: end - begin,
+        }
+    }
+}
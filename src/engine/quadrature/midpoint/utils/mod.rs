@@ -0,0 +1,2 @@
+mod midpoint_points;
+pub use midpoint_points::MidpointPoints;
@@ -1,57 +1,119 @@
+pub mod clenshaw_curtis;
+pub mod gauss_hermite;
+pub mod gauss_jacobi;
+pub mod gauss_kronrod;
+pub mod gauss_laguerre;
+pub mod gauss_legendre;
+pub mod midpoint;
+pub mod monte_carlo;
+pub mod newton_cotes;
+pub mod romberg;
 pub mod simpson;
+pub mod tanh_sinh;
+pub mod trapezoid;
+
+mod fixed_nodes;
+mod special_functions;
 
 use super::{
     helper_equation_traits::{
         EquationOfOneVariable, EquationOfThreeVariable, EquationOfTwoVariable,
     },
     range_generator::RangeGenerator,
-    Bounds, CalculationResult,
+    Bounds, CalculationResult, Resolution,
 };
 use crate::errors::Result;
 
+/// Splits `bounds` of one dimension into panels, `None` means the dimension is empty.
 pub trait GetQuadratureRange {
-    fn get_range_generator(bounds: Bounds, h: f64) -> Result<Option<Box<dyn RangeGenerator>>>;
+    type Range: RangeGenerator;
+
+    fn get_range_generator(bounds: Bounds, resolution: Resolution) -> Result<Option<Self::Range>>;
 }
 
+/// Scales the accumulated `common` sum with the regular step constants and adds `last`.
 pub trait FinalizeCalculation {
     fn finalize(&self, result: CalculationResult) -> Result<f64>;
 }
 
-pub trait GetStepSizeSingleIntegral {
-    fn get_step_size(&self) -> f64;
+/// Order `p` of the error `O(h^p)`, used by the Richardson estimate of `*_with_report`.
+pub trait GetConvergenceOrder {
+    fn get_convergence_order(&self) -> i32;
+}
+
+/// Resolution passed to `get_range_generator` for each dimension.
+pub trait GetResolutionSingleIntegral {
+    fn get_resolution(&self) -> Resolution;
 }
 
+/// Quadrature usable with `engine::calculate_single_integral`.
 pub trait QuadratureSingleIntegral
 where
     Self: GetQuadratureRange
         + FinalizeCalculation
-        + GetStepSizeSingleIntegral
+        + GetResolutionSingleIntegral
         + EquationOfOneVariable,
 {
 }
 
-pub trait GetStepSizeDoubleIntegral {
-    fn get_step_size(&self) -> (f64, f64);
+pub trait GetResolutionDoubleIntegral {
+    fn get_resolution(&self) -> (Resolution, Resolution);
 }
 
+/// Quadrature usable with `engine::calculate_double_integral`, inner bounds are evaluated at
+/// the beginning of every outer panel.
 pub trait QuadratureDoubleIntegral
 where
     Self: GetQuadratureRange
         + FinalizeCalculation
-        + GetStepSizeDoubleIntegral
+        + GetResolutionDoubleIntegral
         + EquationOfTwoVariable,
 {
 }
 
-pub trait GetStepSizeTripleIntegral {
-    fn get_step_size(&self) -> (f64, f64, f64);
+pub trait GetResolutionTripleIntegral {
+    fn get_resolution(&self) -> (Resolution, Resolution, Resolution);
 }
 
+/// Quadrature usable with `engine::calculate_triple_integral`, inner bounds are evaluated at
+/// the beginning of every outer panel.
 pub trait QuadratureTripleIntegral
 where
     Self: GetQuadratureRange
         + FinalizeCalculation
-        + GetStepSizeTripleIntegral
+        + GetResolutionTripleIntegral
         + EquationOfThreeVariable,
 {
 }
+
+/// Rule integrating a whole dimension at once, nested rules evaluate inner bounds at every node.
+/// `bounds` are never reversed or empty, the caller handles both cases. Errors are built with
+/// the context selectors of `errors`, e.g. `errors::ZeroPanelCount.fail()`, an error of the rule
+/// itself is wrapped with `errors::Integrand`.
+pub trait QuadratureRule {
+    fn integrate<E: Fn(f64) -> Result<f64>>(&self, equation: E, bounds: Bounds) -> Result<f64>;
+
+    /// Clears counters of the previous integration, called once before every top-level one.
+    fn reset_counts(&self) {}
+}
+
+impl<R: QuadratureRule> QuadratureRule for &R {
+    fn integrate<E: Fn(f64) -> Result<f64>>(&self, equation: E, bounds: Bounds) -> Result<f64> {
+        (*self).integrate(equation, bounds)
+    }
+
+    fn reset_counts(&self) {
+        (*self).reset_counts()
+    }
+}
+
+/// `QuadratureRule` calculating the equation at its nodes on the rayon thread pool, the nodes
+/// are summed in order, so the result is bitwise identical to `integrate`.
+#[cfg(feature = "parallel")]
+pub trait ParallelQuadratureRule: QuadratureRule {
+    fn integrate_parallel<E: Fn(f64) -> Result<f64> + Sync>(
+        &self,
+        equation: E,
+        bounds: Bounds,
+    ) -> Result<f64>;
+}
@@ -0,0 +1,6 @@
+mod monte_carlo_quadrature;
+mod monte_carlo_sampling;
+mod xoshiro256_star_star;
+pub use monte_carlo_quadrature::MonteCarloQuadrature;
+pub use monte_carlo_sampling::MonteCarloSampling;
+pub use xoshiro256_star_star::Xoshiro256StarStar;
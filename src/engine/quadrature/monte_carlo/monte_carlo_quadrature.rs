@@ -0,0 +1,226 @@
+use fehler::throws;
+use snafu::ensure;
+
+use super::{MonteCarloSampling, Xoshiro256StarStar};
+use crate::{
+    engine::{
+        function_types::{OneVariableFunction, ThreeVariableFunction, TwoVariableFunction},
+        DimensionBounds, IntegrationOutcome,
+    },
+    errors::{self, Error, Result},
+};
+
+const MIN_SAMPLES_PER_CELL: usize = 2;
+
+/// Monte Carlo estimate over nested bounds, every dimension is mapped onto `[0, 1)` and the
+/// equation is weighted with the widths of the bounds. `outcome.error` is the standard error.
+/// Runs with the same seed are reproducible.
+#[derive(Debug, Copy, Clone)]
+pub struct MonteCarloQuadrature {
+    samples: usize,
+    seed: u64,
+    sampling: MonteCarloSampling,
+}
+
+impl MonteCarloQuadrature {
+    #[throws]
+    pub fn new(samples: usize, seed: u64, sampling: MonteCarloSampling) -> Self {
+        ensure!(
+            samples >= MIN_SAMPLES_PER_CELL,
+            errors::InvalidSampleCount {
+                samples,
+                min: MIN_SAMPLES_PER_CELL
+            }
+        );
+        if let MonteCarloSampling::Stratified { strata } = sampling {
+            ensure!(strata > 0, errors::InvalidStrataCount { strata });
+        }
+
+        Self {
+            samples,
+            seed,
+            sampling,
+        }
+    }
+
+    #[throws]
+    pub fn integrate_single<E: OneVariableFunction>(
+        &self,
+        equation: &E,
+        first_integral_begin: f64,
+        first_integral_end: f64,
+    ) -> IntegrationOutcome {
+        self.integrate(1, |u| {
+            let (x, width_x) = Self::map(first_integral_begin, first_integral_end, u[0])?;
+
+            Ok(equation.calculate(x)? * width_x)
+        })?
+    }
+
+    #[throws]
+    pub fn integrate_double<E: TwoVariableFunction, F1: Fn(f64) -> f64, F2: Fn(f64) -> f64>(
+        &self,
+        equation: &E,
+        first_integral_begin: f64,
+        first_integral_end: f64,
+        second_integral_begin: F1,
+        second_integral_end: F2,
+    ) -> IntegrationOutcome {
+        self.integrate(2, |u| {
+            let (x, width_x) = Self::map(first_integral_begin, first_integral_end, u[0])?;
+            let (y, width_y) = Self::map(second_integral_begin(x), second_integral_end(x), u[1])?;
+
+            Ok(equation.calculate(x, y)? * width_x * width_y)
+        })?
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[throws]
+    pub fn integrate_triple<
+        E: ThreeVariableFunction,
+        F1: Fn(f64) -> f64,
+        F2: Fn(f64) -> f64,
+        F3: Fn(f64, f64) -> f64,
+        F4: Fn(f64, f64) -> f64,
+    >(
+        &self,
+        equation: &E,
+        first_integral_begin: f64,
+        first_integral_end: f64,
+        second_integral_begin: F1,
+        second_integral_end: F2,
+        third_integral_begin: F3,
+        third_integral_end: F4,
+    ) -> IntegrationOutcome {
+        self.integrate(3, |u| {
+            let (x, width_x) = Self::map(first_integral_begin, first_integral_end, u[0])?;
+            let (y, width_y) = Self::map(second_integral_begin(x), second_integral_end(x), u[1])?;
+            let (z, width_z) =
+                Self::map(third_integral_begin(x, y), third_integral_end(x, y), u[2])?;
+
+            Ok(equation.calculate(x, y, z)? * width_x * width_y * width_z)
+        })?
+    }
+
+    #[throws]
+    pub fn integrate_multiple<E: Fn(&[f64; N]) -> f64, const N: usize>(
+        &self,
+        equation: &E,
+        dimensions: &[DimensionBounds; N],
+    ) -> IntegrationOutcome {
+        self.integrate(N, |u| {
+            let mut point = [0.; N];
+            let mut width = 1.;
+            for (dimension, bounds) in dimensions.iter().enumerate() {
+                let (value, width_dimension) = Self::map(
+                    (bounds.begin)(&point[..dimension]),
+                    (bounds.end)(&point[..dimension]),
+                    u[dimension],
+                )?;
+                point[dimension] = value;
+                width *= width_dimension;
+            }
+
+            Ok(equation(&point) * width)
+        })?
+    }
+
+    #[throws]
+    fn map(begin: f64, end: f64, u: f64) -> (f64, f64) {
+        ensure!(begin.is_finite(), errors::NonFiniteBound { bound: begin });
+        ensure!(end.is_finite(), errors::NonFiniteBound { bound: end });
+
+        (begin + (end - begin) * u, end - begin)
+    }
+
+    #[throws]
+    fn integrate<S: Fn(&[f64]) -> Result<f64>>(
+        &self,
+        dimension: usize,
+        sample: S,
+    ) -> IntegrationOutcome {
+        let mut rng = Xoshiro256StarStar::new(self.seed);
+        let mut u = vec![0.; dimension];
+
+        match self.sampling {
+            MonteCarloSampling::Plain => {
+                let mut statistics = SampleStatistics::default();
+                for _ in 0..self.samples {
+                    for u in u.iter_mut() {
+                        *u = rng.next_f64();
+                    }
+                    statistics.push(sample(&u)?);
+                }
+
+                IntegrationOutcome {
+                    value: statistics.mean,
+                    error: (statistics.variance() / self.samples as f64).sqrt(),
+                    evaluations: self.samples,
+                    panels: 1,
+                }
+            }
+            MonteCarloSampling::Stratified { strata } => {
+                let min = strata
+                    .checked_pow(dimension as u32)
+                    .and_then(|cells| cells.checked_mul(MIN_SAMPLES_PER_CELL))
+                    .unwrap_or(usize::MAX);
+                ensure!(
+                    self.samples >= min && min != usize::MAX,
+                    errors::InvalidSampleCount {
+                        samples: self.samples,
+                        min
+                    }
+                );
+                let cells = min / MIN_SAMPLES_PER_CELL;
+
+                let mut value = 0.;
+                let mut variance = 0.;
+                for cell in 0..cells {
+                    let cell_samples =
+                        self.samples / cells + usize::from(cell < self.samples % cells);
+
+                    let mut statistics = SampleStatistics::default();
+                    for _ in 0..cell_samples {
+                        let mut index = cell;
+                        for u in u.iter_mut() {
+                            *u = ((index % strata) as f64 + rng.next_f64()) / strata as f64;
+                            index /= strata;
+                        }
+                        statistics.push(sample(&u)?);
+                    }
+
+                    value += statistics.mean;
+                    variance += statistics.variance() / cell_samples as f64;
+                }
+
+                IntegrationOutcome {
+                    value: value / cells as f64,
+                    error: variance.sqrt() / cells as f64,
+                    evaluations: self.samples,
+                    panels: cells,
+                }
+            }
+        }
+    }
+}
+
+// Welford running mean and sum of squared deviations.
+#[derive(Default)]
+struct SampleStatistics {
+    count: usize,
+    mean: f64,
+    squared_deviations: f64,
+}
+
+impl SampleStatistics {
+    fn push(&mut self, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.squared_deviations += delta * (value - self.mean);
+    }
+
+    fn variance(&self) -> f64 {
+        self.squared_deviations / (self.count - 1) as f64
+    }
+}
@@ -0,0 +1,9 @@
+#[derive(Debug, Copy, Clone)]
+pub enum MonteCarloSampling {
+    Plain,
+    /// Splits the unit cube of the mapped domain into `strata` slices per dimension,
+    /// samples are spread evenly over the `strata^N` cells.
+    Stratified {
+        strata: usize,
+    },
+}
@@ -0,0 +1,68 @@
+use fehler::throws;
+
+use super::{utils as newton_cotes_utils, NewtonCotesRule};
+use crate::{
+    engine::{
+        quadrature::{GetConvergenceOrder, QuadratureRule},
+        range_generator::RangeGenerator,
+        Bounds, IndexRangeGenerator, Resolution,
+    },
+    errors::{Error, Result},
+};
+
+pub struct CompositeNewtonCotesQuadrature {
+    x: newton_cotes_utils::NewtonCotesDimension,
+}
+
+impl CompositeNewtonCotesQuadrature {
+    #[throws]
+    pub fn new(h: Resolution, rule: NewtonCotesRule) -> Self {
+        Self {
+            x: newton_cotes_utils::NewtonCotesDimension::new(h, rule)?,
+        }
+    }
+}
+
+impl QuadratureRule for CompositeNewtonCotesQuadrature {
+    #[throws]
+    fn integrate<E: Fn(f64) -> Result<f64>>(&self, equation: E, bounds: Bounds) -> f64 {
+        let panel_width = self.x.panel.panel_width(bounds, 1.);
+        let mut range = if let Some(range) = IndexRangeGenerator::new(bounds, panel_width)? {
+            range
+        } else {
+            return 0.;
+        };
+
+        let mut result = 0.;
+        let mut f_begin = equation(bounds.begin)?;
+        loop {
+            let step = range.next()?;
+            let x = newton_cotes_utils::NewtonCotesPoints::generate(
+                step,
+                bounds,
+                panel_width,
+                self.x.rule,
+            );
+
+            let mut panel = x.weight(0) * f_begin;
+            for i in 1..x.points() {
+                let f = equation(x.node(i))?;
+                panel += x.weight(i) * f;
+                f_begin = f;
+            }
+            result += x.constant() * panel;
+
+            if step.is_last() {
+                break;
+            }
+        }
+
+        result
+    }
+}
+
+impl GetConvergenceOrder for CompositeNewtonCotesQuadrature {
+    fn get_convergence_order(&self) -> i32 {
+        self.x.rule.convergence_order()
+    }
+}
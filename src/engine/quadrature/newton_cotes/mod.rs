@@ -0,0 +1,5 @@
+mod newton_cotes_rule;
+pub use newton_cotes_rule::NewtonCotesRule;
+mod composite_newton_cotes_quadrature;
+pub use composite_newton_cotes_quadrature::CompositeNewtonCotesQuadrature;
+mod utils;
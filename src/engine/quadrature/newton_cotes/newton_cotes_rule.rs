@@ -0,0 +1,72 @@
+use fehler::throws;
+use snafu::ensure;
+
+use crate::errors::{self, Error};
+
+const MIN_POINTS: usize = 3;
+const MAX_POINTS: usize = 6;
+
+const SIMPSON_WEIGHTS: [f64; 3] = [1., 4., 1.];
+const SIMPSON_THREE_EIGHTHS_WEIGHTS: [f64; 4] = [1., 3., 3., 1.];
+const BOOLE_WEIGHTS: [f64; 5] = [7., 32., 12., 32., 7.];
+const SIX_POINT_WEIGHTS: [f64; 6] = [19., 75., 50., 50., 75., 19.];
+
+/// Closed Newton–Cotes rule, `points` equally spaced nodes per panel including both ends.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum NewtonCotesRule {
+    Simpson,
+    SimpsonThreeEighths,
+    Boole,
+    SixPoint,
+}
+
+impl NewtonCotesRule {
+    #[throws]
+    pub fn from_points(points: usize) -> Self {
+        ensure!(
+            (MIN_POINTS..=MAX_POINTS).contains(&points),
+            errors::InvalidPointCount {
+                points,
+                min: MIN_POINTS,
+                max: MAX_POINTS
+            }
+        );
+
+        match points {
+            3 => NewtonCotesRule::Simpson,
+            4 => NewtonCotesRule::SimpsonThreeEighths,
+            5 => NewtonCotesRule::Boole,
+            _ => NewtonCotesRule::SixPoint,
+        }
+    }
+
+    pub fn points(&self) -> usize {
+        self.weights().len()
+    }
+
+    pub fn weights(&self) -> &'static [f64] {
+        match self {
+            NewtonCotesRule::Simpson => &SIMPSON_WEIGHTS,
+            NewtonCotesRule::SimpsonThreeEighths => &SIMPSON_THREE_EIGHTHS_WEIGHTS,
+            NewtonCotesRule::Boole => &BOOLE_WEIGHTS,
+            NewtonCotesRule::SixPoint => &SIX_POINT_WEIGHTS,
+        }
+    }
+
+    /// Factor of `h * Σ w_i f_i`, `h` is the distance between nodes.
+    pub fn constant(&self) -> f64 {
+        match self {
+            NewtonCotesRule::Simpson => 1. / 3.,
+            NewtonCotesRule::SimpsonThreeEighths => 3. / 8.,
+            NewtonCotesRule::Boole => 2. / 45.,
+            NewtonCotesRule::SixPoint => 5. / 288.,
+        }
+    }
+
+    pub fn convergence_order(&self) -> i32 {
+        match self {
+            NewtonCotesRule::Simpson | NewtonCotesRule::SimpsonThreeEighths => 4,
+            NewtonCotesRule::Boole | NewtonCotesRule::SixPoint => 6,
+        }
+    }
+}
@@ -0,0 +1,4 @@
+mod newton_cotes_dimension;
+pub use newton_cotes_dimension::NewtonCotesDimension;
+mod newton_cotes_points;
+pub use newton_cotes_points::NewtonCotesPoints;
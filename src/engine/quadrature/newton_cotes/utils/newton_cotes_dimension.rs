@@ -0,0 +1,27 @@
+use fehler::throws;
+
+use super::super::NewtonCotesRule;
+use crate::{engine::Resolution, errors::Error};
+
+/// Rule of one dimension, `panel` is the step scaled to the panel width of the rule.
+#[derive(Copy, Clone)]
+pub struct NewtonCotesDimension {
+    pub rule: NewtonCotesRule,
+    pub panel: Resolution,
+}
+
+impl NewtonCotesDimension {
+    #[throws]
+    pub fn new(step: Resolution, rule: NewtonCotesRule) -> Self {
+        let panel = match step.validate()? {
+            Resolution::Step(step) => Resolution::Step(step * Self::steps_per_panel(rule)),
+            panels => panels,
+        };
+
+        Self { rule, panel }
+    }
+
+    pub fn steps_per_panel(rule: NewtonCotesRule) -> f64 {
+        (rule.points() - 1) as f64
+    }
+}
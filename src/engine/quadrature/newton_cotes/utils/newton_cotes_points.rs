@@ -0,0 +1,53 @@
+use super::{super::NewtonCotesRule, NewtonCotesDimension};
+use crate::engine::{Bounds, CalculationStep};
+
+pub struct NewtonCotesPoints {
+    pub begin: f64,
+    pub end: f64,
+    pub h: f64,
+    pub rule: NewtonCotesRule,
+}
+
+impl NewtonCotesPoints {
+    /// Panels are equal, the last one only ends exactly on the bound.
+    pub fn generate(
+        step: CalculationStep,
+        bounds: Bounds,
+        panel_width: f64,
+        rule: NewtonCotesRule,
+    ) -> Self {
+        let begin = *step;
+        let end = if step.is_last() {
+            bounds.end
+        } else {
+            begin + panel_width
+        };
+
+        Self {
+            begin,
+            end,
+            h: (end - begin) / NewtonCotesDimension::steps_per_panel(rule),
+            rule,
+        }
+    }
+
+    pub fn points(&self) -> usize {
+        self.rule.points()
+    }
+
+    pub fn node(&self, i: usize) -> f64 {
+        if i + 1 == self.points() {
+            self.end
+        } else {
+            self.begin + i as f64 * self.h
+        }
+    }
+
+    pub fn weight(&self, i: usize) -> f64 {
+        self.rule.weights()[i]
+    }
+
+    pub fn constant(&self) -> f64 {
+        self.rule.constant() * self.h
+    }
+}
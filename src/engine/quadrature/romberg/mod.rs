@@ -0,0 +1,2 @@
+mod romberg_quadrature;
+pub use romberg_quadrature::RombergQuadrature;
@@ -0,0 +1,104 @@
+use fehler::throws;
+use snafu::ensure;
+use std::cell::RefCell;
+
+use crate::{
+    engine::{quadrature::QuadratureRule, Bounds, Tolerance},
+    errors::{self, Error, Result},
+};
+
+// Diagonals of the first rows are too coarse to be compared.
+const MIN_LEVEL: usize = 2;
+// `2^level` panels, the same bound as panel counts of composite rules.
+const MAX_LEVEL: usize = 30;
+
+/// Romberg tableau over trapezoid sums with `2^level` panels, `R(n, m)` is extrapolated from
+/// `R(n, m - 1)` and `R(n - 1, m - 1)`. Stops when two last diagonal entries agree.
+pub struct RombergQuadrature {
+    tolerance: Tolerance,
+    max_level: usize,
+    diagonal: RefCell<Vec<f64>>,
+}
+
+impl RombergQuadrature {
+    #[throws]
+    pub fn new(abs_tol: f64, rel_tol: f64, max_level: usize) -> Self {
+        ensure!(
+            (MIN_LEVEL..=MAX_LEVEL).contains(&max_level),
+            errors::InvalidLevel {
+                level: max_level,
+                min: MIN_LEVEL,
+                max: MAX_LEVEL
+            }
+        );
+
+        Self {
+            tolerance: Tolerance::new(abs_tol, rel_tol)?,
+            max_level,
+            diagonal: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// `R(0, 0), R(1, 1), ...` of the last integrated slice, also kept when the level limit
+    /// is reached.
+    pub fn diagonal(&self) -> Vec<f64> {
+        self.diagonal.borrow().clone()
+    }
+
+    // The diagonal is built locally so nested slices can reuse the same instance.
+    #[throws]
+    fn integrate_tableau<E: Fn(f64) -> Result<f64>>(
+        &self,
+        equation: E,
+        bounds: Bounds,
+        diagonal: &mut Vec<f64>,
+    ) -> f64 {
+        let mut h = bounds.end - bounds.begin;
+        let mut row = vec![h * (equation(bounds.begin)? + equation(bounds.end)?) / 2.];
+        diagonal.push(row[0]);
+
+        let mut error = f64::INFINITY;
+        for level in 1..=self.max_level {
+            h /= 2.;
+
+            let mut sum = 0.;
+            for k in 0..1usize << (level - 1) {
+                sum += equation(bounds.begin + (2 * k + 1) as f64 * h)?;
+            }
+
+            let mut next_row = Vec::with_capacity(level + 1);
+            next_row.push(row[0] / 2. + h * sum);
+            let mut factor = 1.;
+            for m in 1..=level {
+                factor *= 4.;
+                next_row.push(next_row[m - 1] + (next_row[m - 1] - row[m - 1]) / (factor - 1.));
+            }
+
+            let value = next_row[level];
+            error = (value - row[level - 1]).abs();
+            diagonal.push(value);
+            row = next_row;
+
+            if level >= MIN_LEVEL && error <= self.tolerance.target(value) {
+                return value;
+            }
+        }
+
+        errors::LevelLimitReached {
+            limit: self.max_level,
+            error,
+        }
+        .fail()?
+    }
+}
+
+impl QuadratureRule for RombergQuadrature {
+    #[throws]
+    fn integrate<E: Fn(f64) -> Result<f64>>(&self, equation: E, bounds: Bounds) -> f64 {
+        let mut diagonal = Vec::new();
+        let result = self.integrate_tableau(equation, bounds, &mut diagonal);
+        *self.diagonal.borrow_mut() = diagonal;
+
+        result?
+    }
+}
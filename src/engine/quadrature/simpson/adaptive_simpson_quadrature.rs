@@ -0,0 +1,90 @@
+use fehler::throws;
+use snafu::ensure;
+
+use super::utils as simpson_utils;
+use crate::{
+    engine::{quadrature::QuadratureRule, Bounds, Tolerance},
+    errors::{self, Error, Result},
+};
+
+const DEFAULT_MAX_DEPTH: usize = 50;
+
+pub struct AdaptiveSimpsonQuadrature {
+    tolerance: Tolerance,
+    max_depth: usize,
+}
+
+impl AdaptiveSimpsonQuadrature {
+    #[throws]
+    pub fn new(abs_tol: f64, rel_tol: f64) -> Self {
+        Self {
+            tolerance: Tolerance::new(abs_tol, rel_tol)?,
+            max_depth: DEFAULT_MAX_DEPTH,
+        }
+    }
+
+    fn calculate_simpson(points: &simpson_utils::SimpsonPoints, f: [f64; 3]) -> f64 {
+        points.h * (f[0] + 4. * f[1] + f[2]) / 3.
+    }
+
+    /// The target of the panel is recomputed from its refined value, so a vanishing first
+    /// estimate does not shrink the relative target to zero.
+    #[throws]
+    fn integrate_panel<E: Fn(f64) -> Result<f64>>(
+        &self,
+        equation: &E,
+        points: simpson_utils::SimpsonPoints,
+        f: [f64; 3],
+        whole: f64,
+        share: f64,
+        depth: usize,
+    ) -> f64 {
+        let (left, right) = points.split();
+        let f_left = [f[0], equation(left.v1)?, f[1]];
+        let f_right = [f[1], equation(right.v1)?, f[2]];
+
+        let left_value = Self::calculate_simpson(&left, f_left);
+        let right_value = Self::calculate_simpson(&right, f_right);
+        let delta = left_value + right_value - whole;
+
+        ensure!(
+            delta.is_finite(),
+            errors::NonFiniteEstimate {
+                begin: points.v0,
+                end: points.v2
+            }
+        );
+
+        let tolerance = self.tolerance.panel_target(left_value + right_value, share);
+        if delta.abs() <= 15. * tolerance {
+            return left_value + right_value + delta / 15.;
+        }
+
+        ensure!(
+            depth < self.max_depth && left.v1 > left.v0 && right.v1 > right.v0,
+            errors::AdaptiveDepthExceeded {
+                depth,
+                begin: points.v0,
+                end: points.v2
+            }
+        );
+
+        self.integrate_panel(equation, left, f_left, left_value, share / 2., depth + 1)?
+            + self.integrate_panel(equation, right, f_right, right_value, share / 2., depth + 1)?
+    }
+}
+
+impl QuadratureRule for AdaptiveSimpsonQuadrature {
+    #[throws]
+    fn integrate<E: Fn(f64) -> Result<f64>>(&self, equation: E, bounds: Bounds) -> f64 {
+        let points = simpson_utils::SimpsonPoints::span(bounds);
+        let f = [
+            equation(points.v0)?,
+            equation(points.v1)?,
+            equation(points.v2)?,
+        ];
+        let whole = Self::calculate_simpson(&points, f);
+
+        self.integrate_panel(&equation, points, f, whole, 1., 0)?
+    }
+}
@@ -0,0 +1,212 @@
+use fehler::throws;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use snafu::ensure;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use super::utils as simpson_utils;
+#[cfg(feature = "parallel")]
+use crate::engine::quadrature::ParallelQuadratureRule;
+use crate::{
+    engine::{
+        quadrature::{GetConvergenceOrder, QuadratureRule},
+        range_generator::RangeGenerator,
+        Bounds, IndexRangeGenerator, NonFinitePolicy, Resolution,
+    },
+    errors::{self, Error, Result},
+};
+
+pub struct CompositeSimpsonQuadrature {
+    h: Resolution,
+    coarse: bool,
+    non_finite_policy: NonFinitePolicy,
+    evaluations: AtomicUsize,
+    panels: AtomicUsize,
+}
+
+impl CompositeSimpsonQuadrature {
+    #[throws]
+    pub fn new(h: Resolution) -> Self {
+        Self {
+            h: h.validate()?,
+            coarse: false,
+            non_finite_policy: NonFinitePolicy::default(),
+            evaluations: AtomicUsize::new(0),
+            panels: AtomicUsize::new(0),
+        }
+    }
+
+    /// Only changes how non-finite values of a panel are summed, the nodes stay the same.
+    pub fn with_non_finite_policy(mut self, non_finite_policy: NonFinitePolicy) -> Self {
+        self.non_finite_policy = non_finite_policy;
+        self
+    }
+
+    /// Integrates every slice with half the panels `h` gives it, so the Richardson estimate
+    /// compares the same panel boundaries. An odd panel count is `OddPanelCount`.
+    pub fn coarse(mut self) -> Self {
+        self.coarse = true;
+        self
+    }
+
+    /// Equation calls since the last top-level integration started.
+    pub fn evaluations(&self) -> usize {
+        self.evaluations.load(Ordering::Relaxed)
+    }
+
+    /// Panels integrated since the last top-level integration started, summed over every
+    /// integration of a nested dimension.
+    pub fn panels(&self) -> usize {
+        self.panels.load(Ordering::Relaxed)
+    }
+
+    // Counted once per slice, an atomic update per node would dominate cheap equations.
+    fn count(&self, evaluations: usize, panels: usize) {
+        self.evaluations.fetch_add(evaluations, Ordering::Relaxed);
+        self.panels.fetch_add(panels, Ordering::Relaxed);
+    }
+
+    #[throws]
+    fn step_size(&self, bounds: Bounds) -> f64 {
+        let step_size = self.h.step_size(bounds, 2.);
+        if !self.coarse || bounds.end == bounds.begin {
+            return step_size;
+        }
+
+        let panels = IndexRangeGenerator::count_panels(bounds.end - bounds.begin, 2. * step_size)?;
+        ensure!(panels % 2 == 0, errors::OddPanelCount { panels });
+
+        2. * step_size
+    }
+
+    /// Equation at `node`, errors of inner dimensions get `node` in their point.
+    fn evaluate<E: Fn(f64) -> Result<f64>>(equation: &E, node: f64) -> Result<f64> {
+        equation(node).map_err(|error| NonFinitePolicy::with_outer_node(node, error))
+    }
+
+    #[throws]
+    fn panel(&self, x: &simpson_utils::SimpsonPoints, f: [f64; 3]) -> f64 {
+        if self.non_finite_policy == NonFinitePolicy::Propagate {
+            return x.h * (f[0] + 4. * f[1] + f[2]) / 3.;
+        }
+
+        x.h * self.non_finite_policy.weighted_sum(
+            &f,
+            |i| simpson_utils::SIMPSON_WEIGHTS[i],
+            |i| vec![x.node(i)],
+        )? / 3.
+    }
+
+    /// Panels of `bounds`, the end of a panel is the beginning of the next one.
+    #[cfg(feature = "parallel")]
+    #[throws]
+    fn generate_panels(&self, bounds: Bounds) -> Vec<simpson_utils::SimpsonPoints> {
+        let step_size = self.step_size(bounds)?;
+        let mut panels = vec![];
+        if let Some(mut range) = IndexRangeGenerator::new(bounds, 2. * step_size)? {
+            loop {
+                let step = range.next()?;
+                panels.push(simpson_utils::SimpsonPoints::generate(
+                    step, bounds, step_size,
+                ));
+
+                if step.is_last() {
+                    break;
+                }
+            }
+        }
+
+        panels
+    }
+
+    #[cfg(feature = "parallel")]
+    fn nodes(bounds: Bounds, panels: &[simpson_utils::SimpsonPoints]) -> Vec<f64> {
+        let mut nodes = vec![bounds.begin];
+        for x in panels {
+            nodes.push(x.v1);
+            nodes.push(x.v2);
+        }
+
+        nodes
+    }
+
+    /// Sums panels in the order of `integrate`, `values` holds the equation at `nodes`.
+    #[cfg(feature = "parallel")]
+    #[throws]
+    fn sum(&self, panels: &[simpson_utils::SimpsonPoints], values: &[f64]) -> f64 {
+        let mut result = 0.;
+        for (x, f) in panels.iter().zip(values.windows(3).step_by(2)) {
+            result += self.panel(x, [f[0], f[1], f[2]])?;
+        }
+
+        result
+    }
+}
+
+impl QuadratureRule for CompositeSimpsonQuadrature {
+    #[throws]
+    fn integrate<E: Fn(f64) -> Result<f64>>(&self, equation: E, bounds: Bounds) -> f64 {
+        let step_size = self.step_size(bounds)?;
+        let mut range = if let Some(range) = IndexRangeGenerator::new(bounds, 2. * step_size)? {
+            range
+        } else {
+            return 0.;
+        };
+
+        let mut result = 0.;
+        let mut panels = 0;
+        let mut f_v0 = Self::evaluate(&equation, bounds.begin)?;
+        loop {
+            let step = range.next()?;
+            let x = simpson_utils::SimpsonPoints::generate(step, bounds, step_size);
+
+            let f_v1 = Self::evaluate(&equation, x.v1)?;
+            let f_v2 = Self::evaluate(&equation, x.v2)?;
+            panels += 1;
+
+            result += self.panel(&x, [f_v0, f_v1, f_v2])?;
+            f_v0 = f_v2;
+
+            if step.is_last() {
+                break;
+            }
+        }
+        self.count(2 * panels + 1, panels);
+
+        result
+    }
+
+    fn reset_counts(&self) {
+        self.evaluations.store(0, Ordering::Relaxed);
+        self.panels.store(0, Ordering::Relaxed);
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl ParallelQuadratureRule for CompositeSimpsonQuadrature {
+    #[throws]
+    fn integrate_parallel<E: Fn(f64) -> Result<f64> + Sync>(
+        &self,
+        equation: E,
+        bounds: Bounds,
+    ) -> f64 {
+        let panels = self.generate_panels(bounds)?;
+        if panels.is_empty() {
+            return 0.;
+        }
+
+        let values = Self::nodes(bounds, &panels)
+            .into_par_iter()
+            .map(|node| Self::evaluate(&equation, node))
+            .collect::<Result<Vec<_>>>()?;
+        self.count(values.len(), panels.len());
+
+        self.sum(&panels, &values)?
+    }
+}
+
+impl GetConvergenceOrder for CompositeSimpsonQuadrature {
+    fn get_convergence_order(&self) -> i32 {
+        4
+    }
+}
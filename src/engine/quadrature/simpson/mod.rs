@@ -1,8 +1,5 @@
-mod simpson_quadrature_single_integral;
-pub use simpson_quadrature_single_integral::SimpsonQuadratureSingleIntegral;
-mod simpson_quadrature_double_integral;
-pub use simpson_quadrature_double_integral::SimpsonQuadratureDoubleIntegral;
-mod simpson_quadrature_triple_integral;
-pub use simpson_quadrature_triple_integral::SimpsonQuadratureTripleIntegral;
-mod simpson_range;
+mod adaptive_simpson_quadrature;
+pub use adaptive_simpson_quadrature::AdaptiveSimpsonQuadrature;
+mod composite_simpson_quadrature;
+pub use composite_simpson_quadrature::CompositeSimpsonQuadrature;
 mod utils;
@@ -1,2 +1,4 @@
 mod simpson_points;
 pub use simpson_points::SimpsonPoints;
+
+pub const SIMPSON_WEIGHTS: [f64; 3] = [1., 4., 1.];
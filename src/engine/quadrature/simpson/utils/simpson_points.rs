@@ -8,16 +8,10 @@ pub struct SimpsonPoints {
 }
 
 impl SimpsonPoints {
-    pub fn generate(
-        step: CalculationStep,
-        bounds: Bounds,
-        step_size: f64,
-        is_last_step: &mut bool,
-    ) -> Self {
+    /// Panel beginning at `step`, the last one ends exactly on the bound.
+    pub fn generate(step: CalculationStep, bounds: Bounds, step_size: f64) -> Self {
         let v0 = *step;
         let (v1, v2, h) = if step.is_last() {
-            *is_last_step = true;
-
             let v2 = bounds.end;
             let h = (v2 - v0) / 2.;
             let v1 = v0 + h;
@@ -30,4 +24,32 @@ impl SimpsonPoints {
 
         Self { v0, v1, v2, h }
     }
+
+    pub fn node(&self, i: usize) -> f64 {
+        [self.v0, self.v1, self.v2][i]
+    }
+
+    pub fn span(bounds: Bounds) -> Self {
+        let h = (bounds.end - bounds.begin) / 2.;
+
+        Self {
+            v0: bounds.begin,
+            v1: bounds.begin + h,
+            v2: bounds.end,
+            h,
+        }
+    }
+
+    pub fn split(&self) -> (Self, Self) {
+        (
+            Self::span(Bounds {
+                begin: self.v0,
+                end: self.v1,
+            }),
+            Self::span(Bounds {
+                begin: self.v1,
+                end: self.v2,
+            }),
+        )
+    }
 }
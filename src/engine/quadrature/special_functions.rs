@@ -0,0 +1,30 @@
+use std::f64::consts::PI;
+
+const LANCZOS_G: f64 = 7.;
+const LANCZOS_COEFFICIENTS: [f64; 9] = [
+    0.999_999_999_999_809_9,
+    676.520_368_121_885_1,
+    -1_259.139_216_722_402_8,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507_343_278_686_905,
+    -0.138_571_095_265_720_12,
+    9.984_369_578_019_572e-6,
+    1.505_632_735_149_311_6e-7,
+];
+
+/// `ln|Γ(x)|` by the Lanczos approximation, the reflection formula is used below `0.5`.
+pub fn ln_gamma(x: f64) -> f64 {
+    if x < 0.5 {
+        return (PI / (PI * x).sin().abs()).ln() - ln_gamma(1. - x);
+    }
+
+    let x = x - 1.;
+    let mut sum = LANCZOS_COEFFICIENTS[0];
+    for (i, coefficient) in LANCZOS_COEFFICIENTS.iter().enumerate().skip(1) {
+        sum += coefficient / (x + i as f64);
+    }
+
+    let t = x + LANCZOS_G + 0.5;
+    0.5 * (2. * PI).ln() + (x + 0.5) * t.ln() - t + sum.ln()
+}
@@ -0,0 +1,2 @@
+mod tanh_sinh_quadrature;
+pub use tanh_sinh_quadrature::TanhSinhQuadrature;
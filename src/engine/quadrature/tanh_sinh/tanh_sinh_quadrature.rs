@@ -0,0 +1,114 @@
+use fehler::throws;
+use snafu::ensure;
+use std::f64::consts::FRAC_PI_2;
+
+use crate::{
+    engine::{quadrature::QuadratureRule, Bounds, Tolerance},
+    errors::{self, Error, Result},
+};
+
+// Beyond it the distance of a node to the nearest bound underflows.
+const T_MAX: f64 = 6.5;
+// Level 0 has nothing to compare with.
+const MIN_LEVEL: usize = 1;
+// `2 * T_MAX * 2^level` nodes, the last level already takes ten million per slice.
+const MAX_LEVEL: usize = 20;
+
+/// Double exponential quadrature, `x = c + r * tanh(π/2 * sinh(t))` on the grid `t = k * h`.
+/// The step is halved every level, nodes are never placed on the bounds.
+#[derive(Copy, Clone)]
+pub struct TanhSinhQuadrature {
+    tolerance: Tolerance,
+    max_level: usize,
+}
+
+impl TanhSinhQuadrature {
+    #[throws]
+    pub fn new(abs_tol: f64, rel_tol: f64, max_level: usize) -> Self {
+        ensure!(
+            (MIN_LEVEL..=MAX_LEVEL).contains(&max_level),
+            errors::InvalidLevel {
+                level: max_level,
+                min: MIN_LEVEL,
+                max: MAX_LEVEL
+            }
+        );
+
+        Self {
+            tolerance: Tolerance::new(abs_tol, rel_tol)?,
+            max_level,
+        }
+    }
+
+    #[throws]
+    fn sum_nodes<E: Fn(f64) -> Result<f64>>(
+        equation: &E,
+        bounds: Bounds,
+        h: f64,
+        first: usize,
+        stride: usize,
+    ) -> f64 {
+        let radius = (bounds.end - bounds.begin) / 2.;
+        let mut sum = 0.;
+
+        let mut k = first;
+        while k as f64 * h <= T_MAX {
+            let t = k as f64 * h;
+            // q = exp(-2u), the distance to the bound is r * (1 - tanh(u)) = r * 2q / (1 + q)
+            let q = (-2. * FRAC_PI_2 * t.sinh()).exp();
+            let distance = radius * 2. * q / (1. + q);
+            let weight = radius * FRAC_PI_2 * t.cosh() * 4. * q / (1. + q).powi(2);
+
+            let left = bounds.begin + distance;
+            let right = bounds.end - distance;
+            let is_left_inside = left > bounds.begin;
+            let is_right_inside = right < bounds.end;
+            if !is_left_inside && !is_right_inside {
+                break;
+            }
+
+            if is_left_inside {
+                sum += weight * equation(left)?;
+            }
+            if is_right_inside {
+                sum += weight * equation(right)?;
+            }
+
+            k += stride;
+        }
+
+        sum
+    }
+}
+
+impl QuadratureRule for TanhSinhQuadrature {
+    #[throws]
+    fn integrate<E: Fn(f64) -> Result<f64>>(&self, equation: E, bounds: Bounds) -> f64 {
+        let radius = (bounds.end - bounds.begin) / 2.;
+
+        let mut h = 1.;
+        let mut sum = radius * FRAC_PI_2 * equation(bounds.begin + radius)?
+            + Self::sum_nodes(&equation, bounds, h, 1, 1)?;
+        let mut value = h * sum;
+        let mut error = f64::INFINITY;
+
+        for _ in 0..self.max_level {
+            h /= 2.;
+            sum += Self::sum_nodes(&equation, bounds, h, 1, 2)?;
+
+            let refined = h * sum;
+            error = (refined - value).abs();
+            value = refined;
+
+            if error <= self.tolerance.target(value) {
+                return value;
+            }
+        }
+
+        errors::LevelLimitReached {
+            limit: self.max_level,
+            error,
+        }
+        .fail()?
+    }
+}
@@ -0,0 +1,57 @@
+use fehler::throws;
+
+use super::utils as trapezoid_utils;
+use crate::{
+    engine::{
+        quadrature::{GetConvergenceOrder, QuadratureRule},
+        range_generator::RangeGenerator,
+        Bounds, IndexRangeGenerator, Resolution,
+    },
+    errors::{Error, Result},
+};
+
+pub struct CompositeTrapezoidQuadrature {
+    h: Resolution,
+}
+
+impl CompositeTrapezoidQuadrature {
+    #[throws]
+    pub fn new(h: Resolution) -> Self {
+        Self { h: h.validate()? }
+    }
+}
+
+impl QuadratureRule for CompositeTrapezoidQuadrature {
+    #[throws]
+    fn integrate<E: Fn(f64) -> Result<f64>>(&self, equation: E, bounds: Bounds) -> f64 {
+        let panel_width = self.h.panel_width(bounds, 1.);
+        let mut range = if let Some(range) = IndexRangeGenerator::new(bounds, panel_width)? {
+            range
+        } else {
+            return 0.;
+        };
+
+        let mut result = 0.;
+        let mut f_v0 = equation(bounds.begin)?;
+        loop {
+            let step = range.next()?;
+            let x = trapezoid_utils::TrapezoidPoints::generate(step, bounds, panel_width);
+
+            let f_v1 = equation(x.v1)?;
+            result += x.h * (f_v0 + f_v1) / 2.;
+            f_v0 = f_v1;
+
+            if step.is_last() {
+                break;
+            }
+        }
+
+        result
+    }
+}
+
+impl GetConvergenceOrder for CompositeTrapezoidQuadrature {
+    fn get_convergence_order(&self) -> i32 {
+        2
+    }
+}
@@ -0,0 +1,3 @@
+mod composite_trapezoid_quadrature;
+pub use composite_trapezoid_quadrature::CompositeTrapezoidQuadrature;
+mod utils;
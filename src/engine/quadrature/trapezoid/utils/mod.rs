@@ -0,0 +1,2 @@
+mod trapezoid_points;
+pub use trapezoid_points::TrapezoidPoints;
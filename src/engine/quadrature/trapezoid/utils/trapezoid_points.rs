@@ -0,0 +1,20 @@
+use crate::engine::{Bounds, CalculationStep};
+
+pub struct TrapezoidPoints {
+    pub v1: f64,
+    pub h: f64,
+}
+
+impl TrapezoidPoints {
+    /// Panel beginning at `step`, the last one ends exactly on the bound.
+    pub fn generate(step: CalculationStep, bounds: Bounds, panel_width: f64) -> Self {
+        let v0 = *step;
+        let v1 = if step.is_last() {
+            bounds.end
+        } else {
+            v0 + panel_width
+        };
+
+        Self { v1, h: v1 - v0 }
+    }
+}
@@ -2,6 +2,8 @@ pub use super::CalculationStep;
 
 use crate::errors::Result;
 
+/// Yields panel beginnings, the final panel is `CalculationStep::Last` and ends at `bounds.end`.
+/// Errors are built with the context selectors of `errors`, like those of `QuadratureRule`.
 pub trait RangeGenerator {
     fn next(&mut self) -> Result<CalculationStep>;
 }
@@ -0,0 +1,48 @@
+use fehler::throws;
+use snafu::ensure;
+
+use super::{Bounds, IndexRangeGenerator, StepValidator};
+use crate::errors::{self, Error};
+
+#[derive(Debug, Copy, Clone)]
+pub enum Resolution {
+    Step(f64),
+    Panels(usize),
+}
+
+impl From<f64> for Resolution {
+    fn from(step: f64) -> Self {
+        Resolution::Step(step)
+    }
+}
+
+impl Resolution {
+    #[throws]
+    pub fn validate(self) -> Self {
+        match self {
+            Resolution::Step(step) => Resolution::Step(StepValidator::validate(step)?),
+            Resolution::Panels(panels) => {
+                ensure!(panels > 0, errors::ZeroPanelCount);
+                self
+            }
+        }
+    }
+
+    pub fn step_size(&self, bounds: Bounds, steps_per_panel: f64) -> f64 {
+        match self {
+            Resolution::Step(step) => {
+                IndexRangeGenerator::equal_panel_width(
+                    bounds.end - bounds.begin,
+                    step * steps_per_panel,
+                ) / steps_per_panel
+            }
+            Resolution::Panels(panels) => {
+                (bounds.end - bounds.begin) / (*panels as f64 * steps_per_panel)
+            }
+        }
+    }
+
+    pub fn panel_width(&self, bounds: Bounds, steps_per_panel: f64) -> f64 {
+        self.step_size(bounds, steps_per_panel) * steps_per_panel
+    }
+}
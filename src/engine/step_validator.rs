@@ -0,0 +1,16 @@
+use fehler::throws;
+use snafu::ensure;
+
+use crate::errors::{self, Error};
+
+pub struct StepValidator;
+
+impl StepValidator {
+    #[throws]
+    pub fn validate(step: f64) -> f64 {
+        ensure!(step.is_finite(), errors::NonFiniteStep { step });
+        ensure!(step > 0., errors::NonPositiveStep { step });
+
+        step
+    }
+}
@@ -0,0 +1,31 @@
+use fehler::throws;
+use snafu::ensure;
+
+use crate::errors::{self, Error};
+
+#[derive(Debug, Copy, Clone)]
+pub struct Tolerance {
+    pub abs_tol: f64,
+    pub rel_tol: f64,
+}
+
+impl Tolerance {
+    #[throws]
+    pub fn new(abs_tol: f64, rel_tol: f64) -> Self {
+        ensure!(
+            abs_tol >= 0. && rel_tol >= 0. && (abs_tol > 0. || rel_tol > 0.),
+            errors::InvalidTolerance { abs_tol, rel_tol }
+        );
+
+        Self { abs_tol, rel_tol }
+    }
+
+    pub fn target(&self, estimate: f64) -> f64 {
+        self.abs_tol.max(self.rel_tol * estimate.abs())
+    }
+
+    /// Target of a panel holding `share` of `abs_tol`, `estimate` is the value of the panel.
+    pub fn panel_target(&self, estimate: f64, share: f64) -> f64 {
+        (self.abs_tol * share).max(self.rel_tol * estimate.abs())
+    }
+}
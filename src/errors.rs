@@ -1,7 +1,7 @@
 use snafu::{Backtrace, Snafu};
 
 #[derive(Debug, Snafu)]
-#[snafu(visibility(pub(crate)))]
+#[snafu(visibility(pub))]
 pub enum Error {
     #[snafu(display("RangeGenerator step{} out of end bound{}", step, end))]
     RangeGeneratorOutOfBounds {
@@ -16,6 +16,135 @@ pub enum Error {
         end: f64,
         backtrace: Backtrace,
     },
+
+    #[snafu(display("Invalid tolerance: abs_tol {}, rel_tol {}", abs_tol, rel_tol))]
+    InvalidTolerance {
+        abs_tol: f64,
+        rel_tol: f64,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display(
+        "Tolerance not reached after {} bisections on [{}, {}]",
+        depth,
+        begin,
+        end
+    ))]
+    AdaptiveDepthExceeded {
+        depth: usize,
+        begin: f64,
+        end: f64,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("Point count {} is out of range [{}, {}]", points, min, max))]
+    InvalidPointCount {
+        points: usize,
+        min: usize,
+        max: usize,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("Subdivision limit {} reached with error estimate {}", limit, error))]
+    SubdivisionLimitReached {
+        limit: usize,
+        error: f64,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("Step {} must be positive", step))]
+    NonPositiveStep { step: f64, backtrace: Backtrace },
+
+    #[snafu(display("Step {} must be finite", step))]
+    NonFiniteStep { step: f64, backtrace: Backtrace },
+
+    #[snafu(display("Bound {} must be finite", bound))]
+    NonFiniteBound { bound: f64, backtrace: Backtrace },
+
+    #[snafu(display("Panel count must be positive"))]
+    ZeroPanelCount { backtrace: Backtrace },
+
+    #[snafu(display("Integrand failed: {}", source))]
+    Integrand {
+        source: IntegrandError,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("Integrand is not finite at {:?}", point))]
+    NonFiniteIntegrand {
+        point: Vec<f64>,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display(
+        "Inner bound {} is infinite, only the outer dimension supports infinite bounds",
+        bound
+    ))]
+    InfiniteInnerBound { bound: f64, backtrace: Backtrace },
+
+    #[snafu(display("Level limit {} reached with error estimate {}", limit, error))]
+    LevelLimitReached {
+        limit: usize,
+        error: f64,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("Weight exponent {} must be greater than -1", exponent))]
+    InvalidWeightExponent { exponent: f64, backtrace: Backtrace },
+
+    #[snafu(display("Pole {} must lie strictly between bounds {} and {}", pole, begin, end))]
+    InvalidPole {
+        pole: f64,
+        begin: f64,
+        end: f64,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("Sample count {} must be at least {}", samples, min))]
+    InvalidSampleCount {
+        samples: usize,
+        min: usize,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("Strata count {} must be positive", strata))]
+    InvalidStrataCount { strata: usize, backtrace: Backtrace },
+
+    #[snafu(display("Subdivision limit {} must be positive", limit))]
+    InvalidSubdivisionLimit { limit: usize, backtrace: Backtrace },
+
+    #[snafu(display("Resolution gives {} panels, at most {} are supported", panels, max))]
+    TooManyPanels {
+        panels: f64,
+        max: usize,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("Panel count {} must be even to estimate the error", panels))]
+    OddPanelCount { panels: usize, backtrace: Backtrace },
+
+    #[snafu(display("Simpson estimate is not finite on [{}, {}]", begin, end))]
+    NonFiniteEstimate {
+        begin: f64,
+        end: f64,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("Integrand is not finite at any point of the panel at {:?}", point))]
+    NonFinitePanel {
+        point: Vec<f64>,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("Level {} is out of range [{}, {}]", level, min, max))]
+    InvalidLevel {
+        level: usize,
+        min: usize,
+        max: usize,
+        backtrace: Backtrace,
+    },
 }
 
 pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+pub type IntegrandError = Box<dyn std::error::Error + Send + Sync>;
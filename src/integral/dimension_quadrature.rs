@@ -0,0 +1,110 @@
+use fehler::throws;
+
+use super::Rule;
+use crate::{
+    engine::{
+        quadrature::{
+            clenshaw_curtis::ClenshawCurtisQuadrature,
+            gauss_jacobi::GaussJacobiQuadrature,
+            gauss_kronrod::GaussKronrodQuadrature,
+            gauss_legendre::CompositeGaussLegendreQuadrature,
+            romberg::RombergQuadrature,
+            simpson::{AdaptiveSimpsonQuadrature, CompositeSimpsonQuadrature},
+            tanh_sinh::TanhSinhQuadrature,
+            QuadratureRule,
+        },
+        Bounds,
+    },
+    errors::{Error, Result},
+};
+
+pub enum DimensionQuadrature {
+    Simpson(CompositeSimpsonQuadrature),
+    GaussLegendre(CompositeGaussLegendreQuadrature),
+    AdaptiveSimpson(AdaptiveSimpsonQuadrature),
+    GaussKronrod(GaussKronrodQuadrature),
+    TanhSinh(TanhSinhQuadrature),
+    Romberg(RombergQuadrature),
+    ClenshawCurtis(ClenshawCurtisQuadrature),
+    GaussJacobi(GaussJacobiQuadrature),
+}
+
+impl DimensionQuadrature {
+    #[throws]
+    pub fn new(rule: Rule) -> Self {
+        match rule {
+            Rule::Simpson { step } => {
+                DimensionQuadrature::Simpson(CompositeSimpsonQuadrature::new(step)?)
+            }
+            Rule::GaussLegendre { step, points } => DimensionQuadrature::GaussLegendre(
+                CompositeGaussLegendreQuadrature::new(step, points)?,
+            ),
+            Rule::AdaptiveSimpson { abs_tol, rel_tol } => DimensionQuadrature::AdaptiveSimpson(
+                AdaptiveSimpsonQuadrature::new(abs_tol, rel_tol)?,
+            ),
+            Rule::GaussKronrod {
+                rule,
+                abs_tol,
+                rel_tol,
+                limit,
+            } => DimensionQuadrature::GaussKronrod(GaussKronrodQuadrature::new(
+                rule, abs_tol, rel_tol, limit,
+            )?),
+            Rule::TanhSinh {
+                abs_tol,
+                rel_tol,
+                max_level,
+            } => {
+                DimensionQuadrature::TanhSinh(TanhSinhQuadrature::new(abs_tol, rel_tol, max_level)?)
+            }
+            Rule::Romberg {
+                abs_tol,
+                rel_tol,
+                max_level,
+            } => DimensionQuadrature::Romberg(RombergQuadrature::new(abs_tol, rel_tol, max_level)?),
+            Rule::ClenshawCurtis {
+                abs_tol,
+                rel_tol,
+                max_level,
+            } => DimensionQuadrature::ClenshawCurtis(ClenshawCurtisQuadrature::new(
+                abs_tol, rel_tol, max_level,
+            )?),
+            Rule::GaussJacobi {
+                points,
+                alpha,
+                beta,
+            } => DimensionQuadrature::GaussJacobi(GaussJacobiQuadrature::new(points, alpha, beta)?),
+        }
+    }
+}
+
+impl QuadratureRule for DimensionQuadrature {
+    #[throws]
+    fn integrate<E: Fn(f64) -> Result<f64>>(&self, equation: E, bounds: Bounds) -> f64 {
+        match self {
+            DimensionQuadrature::Simpson(quadrature) => quadrature.integrate(equation, bounds)?,
+            DimensionQuadrature::GaussLegendre(quadrature) => {
+                quadrature.integrate(equation, bounds)?
+            }
+            DimensionQuadrature::AdaptiveSimpson(quadrature) => {
+                quadrature.integrate(equation, bounds)?
+            }
+            DimensionQuadrature::GaussKronrod(quadrature) => {
+                quadrature.integrate(equation, bounds)?
+            }
+            DimensionQuadrature::TanhSinh(quadrature) => quadrature.integrate(equation, bounds)?,
+            DimensionQuadrature::Romberg(quadrature) => quadrature.integrate(equation, bounds)?,
+            DimensionQuadrature::ClenshawCurtis(quadrature) => {
+                quadrature.integrate(equation, bounds)?
+            }
+            DimensionQuadrature::GaussJacobi(quadrature) => {
+                quadrature.integrate(equation, bounds)?
+            }
+        }
+    }
+    fn reset_counts(&self) {
+        if let DimensionQuadrature::Simpson(quadrature) = self {
+            quadrature.reset_counts();
+        }
+    }
+}
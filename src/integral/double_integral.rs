@@ -0,0 +1,96 @@
+use fehler::throws;
+
+use super::{dimension_quadrature::DimensionQuadrature, NoRule, Rule, TripleIntegral};
+use crate::{engine, errors::Error};
+
+/// `X` and `Y` are `NoRule` until the rule of the dimension is chosen.
+pub struct DoubleIntegral<E, F1: Fn(f64) -> f64, F2: Fn(f64) -> f64, X = NoRule, Y = NoRule> {
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+    x_rule: X,
+    y_rule: Y,
+}
+
+impl<E, F1: Fn(f64) -> f64, F2: Fn(f64) -> f64, X, Y> DoubleIntegral<E, F1, F2, X, Y> {
+    pub(crate) fn new(
+        equation: E,
+        first_integral_begin: f64,
+        first_integral_end: f64,
+        second_integral_begin: F1,
+        second_integral_end: F2,
+        (x_rule, y_rule): (X, Y),
+    ) -> Self {
+        Self {
+            equation,
+            first_integral_begin,
+            first_integral_end,
+            second_integral_begin,
+            second_integral_end,
+            x_rule,
+            y_rule,
+        }
+    }
+
+    pub fn over_z<F3: Fn(f64, f64) -> f64, F4: Fn(f64, f64) -> f64>(
+        self,
+        third_integral_begin: F3,
+        third_integral_end: F4,
+    ) -> TripleIntegral<E, F1, F2, F3, F4, X, Y, NoRule> {
+        TripleIntegral::new(
+            self.equation,
+            self.first_integral_begin,
+            self.first_integral_end,
+            self.second_integral_begin,
+            self.second_integral_end,
+            third_integral_begin,
+            third_integral_end,
+            (self.x_rule, self.y_rule, NoRule),
+        )
+    }
+
+    pub fn with_rule(self, rule: Rule) -> DoubleIntegral<E, F1, F2, Rule, Rule> {
+        self.with_x_rule(rule).with_y_rule(rule)
+    }
+
+    pub fn with_x_rule(self, rule: Rule) -> DoubleIntegral<E, F1, F2, Rule, Y> {
+        DoubleIntegral::new(
+            self.equation,
+            self.first_integral_begin,
+            self.first_integral_end,
+            self.second_integral_begin,
+            self.second_integral_end,
+            (rule, self.y_rule),
+        )
+    }
+
+    pub fn with_y_rule(self, rule: Rule) -> DoubleIntegral<E, F1, F2, X, Rule> {
+        DoubleIntegral::new(
+            self.equation,
+            self.first_integral_begin,
+            self.first_integral_end,
+            self.second_integral_begin,
+            self.second_integral_end,
+            (self.x_rule, rule),
+        )
+    }
+}
+
+impl<E: Fn(f64, f64) -> f64, F1: Fn(f64) -> f64, F2: Fn(f64) -> f64>
+    DoubleIntegral<E, F1, F2, Rule, Rule>
+{
+    #[throws]
+    pub fn compute(self) -> f64 {
+        engine::calculate_double_integral_with_rules(
+            DimensionQuadrature::new(self.x_rule)?,
+            DimensionQuadrature::new(self.y_rule)?,
+            self.equation,
+            self.first_integral_begin,
+            self.first_integral_end,
+            self.second_integral_begin,
+            self.second_integral_end,
+        )?
+    }
+}
@@ -0,0 +1,24 @@
+mod rule;
+pub use rule::{NoRule, Rule};
+mod dimension_quadrature;
+pub(crate) use dimension_quadrature::DimensionQuadrature;
+mod single_integral;
+pub use single_integral::SingleIntegral;
+mod double_integral;
+pub use double_integral::DoubleIntegral;
+mod triple_integral;
+pub use triple_integral::TripleIntegral;
+
+pub struct Integral<E> {
+    equation: E,
+}
+
+impl<E> Integral<E> {
+    pub fn new(equation: E) -> Self {
+        Self { equation }
+    }
+
+    pub fn over_x(self, begin: f64, end: f64) -> SingleIntegral<E, NoRule> {
+        SingleIntegral::new(self.equation, begin, end, NoRule)
+    }
+}
@@ -0,0 +1,69 @@
+use crate::engine::{quadrature::gauss_kronrod::GaussKronrodRule, Resolution};
+
+/// Rule of a dimension that is not chosen yet, `compute` needs a `Rule` for every dimension.
+///
+/// ```compile_fail
+/// use sequential_integration::{Integral, Rule};
+///
+/// Integral::new(|x: f64, y: f64| x * y)
+///     .over_x(0., 1.)
+///     .with_x_rule(Rule::simpson(0.1))
+///     .over_y(|_x| 0., |_x| 1.)
+///     .compute();
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct NoRule;
+
+#[derive(Debug, Copy, Clone)]
+pub enum Rule {
+    Simpson {
+        step: Resolution,
+    },
+    GaussLegendre {
+        step: Resolution,
+        points: usize,
+    },
+    AdaptiveSimpson {
+        abs_tol: f64,
+        rel_tol: f64,
+    },
+    GaussKronrod {
+        rule: GaussKronrodRule,
+        abs_tol: f64,
+        rel_tol: f64,
+        limit: usize,
+    },
+    TanhSinh {
+        abs_tol: f64,
+        rel_tol: f64,
+        max_level: usize,
+    },
+    Romberg {
+        abs_tol: f64,
+        rel_tol: f64,
+        max_level: usize,
+    },
+    ClenshawCurtis {
+        abs_tol: f64,
+        rel_tol: f64,
+        max_level: usize,
+    },
+    GaussJacobi {
+        points: usize,
+        alpha: f64,
+        beta: f64,
+    },
+}
+
+impl Rule {
+    pub fn simpson<R: Into<Resolution>>(step: R) -> Self {
+        Rule::Simpson { step: step.into() }
+    }
+
+    pub fn gauss_legendre<R: Into<Resolution>>(step: R, points: usize) -> Self {
+        Rule::GaussLegendre {
+            step: step.into(),
+            points,
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use fehler::throws;
+
+use super::{dimension_quadrature::DimensionQuadrature, DoubleIntegral, NoRule, Rule};
+use crate::{engine, errors::Error};
+
+/// `X` is `NoRule` until the rule of the dimension is chosen.
+pub struct SingleIntegral<E, X = NoRule> {
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    x_rule: X,
+}
+
+impl<E, X> SingleIntegral<E, X> {
+    pub(crate) fn new(
+        equation: E,
+        first_integral_begin: f64,
+        first_integral_end: f64,
+        x_rule: X,
+    ) -> Self {
+        Self {
+            equation,
+            first_integral_begin,
+            first_integral_end,
+            x_rule,
+        }
+    }
+
+    pub fn over_y<F1: Fn(f64) -> f64, F2: Fn(f64) -> f64>(
+        self,
+        second_integral_begin: F1,
+        second_integral_end: F2,
+    ) -> DoubleIntegral<E, F1, F2, X, NoRule> {
+        DoubleIntegral::new(
+            self.equation,
+            self.first_integral_begin,
+            self.first_integral_end,
+            second_integral_begin,
+            second_integral_end,
+            (self.x_rule, NoRule),
+        )
+    }
+
+    pub fn with_rule(self, rule: Rule) -> SingleIntegral<E, Rule> {
+        self.with_x_rule(rule)
+    }
+
+    pub fn with_x_rule(self, rule: Rule) -> SingleIntegral<E, Rule> {
+        SingleIntegral::new(
+            self.equation,
+            self.first_integral_begin,
+            self.first_integral_end,
+            rule,
+        )
+    }
+}
+
+impl<E: Fn(f64) -> f64> SingleIntegral<E, Rule> {
+    #[throws]
+    pub fn compute(self) -> f64 {
+        engine::calculate_single_integral_with_rule(
+            DimensionQuadrature::new(self.x_rule)?,
+            self.equation,
+            self.first_integral_begin,
+            self.first_integral_end,
+        )?
+    }
+}
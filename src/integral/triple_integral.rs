@@ -0,0 +1,132 @@
+use fehler::throws;
+
+use super::{dimension_quadrature::DimensionQuadrature, NoRule, Rule};
+use crate::{engine, errors::Error};
+
+/// `X`, `Y` and `Z` are `NoRule` until the rule of the dimension is chosen.
+pub struct TripleIntegral<
+    E,
+    F1: Fn(f64) -> f64,
+    F2: Fn(f64) -> f64,
+    F3: Fn(f64, f64) -> f64,
+    F4: Fn(f64, f64) -> f64,
+    X = NoRule,
+    Y = NoRule,
+    Z = NoRule,
+> {
+    equation: E,
+    first_integral_begin: f64,
+    first_integral_end: f64,
+    second_integral_begin: F1,
+    second_integral_end: F2,
+    third_integral_begin: F3,
+    third_integral_end: F4,
+    x_rule: X,
+    y_rule: Y,
+    z_rule: Z,
+}
+
+impl<
+        E,
+        F1: Fn(f64) -> f64,
+        F2: Fn(f64) -> f64,
+        F3: Fn(f64, f64) -> f64,
+        F4: Fn(f64, f64) -> f64,
+        X,
+        Y,
+        Z,
+    > TripleIntegral<E, F1, F2, F3, F4, X, Y, Z>
+{
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        equation: E,
+        first_integral_begin: f64,
+        first_integral_end: f64,
+        second_integral_begin: F1,
+        second_integral_end: F2,
+        third_integral_begin: F3,
+        third_integral_end: F4,
+        (x_rule, y_rule, z_rule): (X, Y, Z),
+    ) -> Self {
+        Self {
+            equation,
+            first_integral_begin,
+            first_integral_end,
+            second_integral_begin,
+            second_integral_end,
+            third_integral_begin,
+            third_integral_end,
+            x_rule,
+            y_rule,
+            z_rule,
+        }
+    }
+
+    pub fn with_rule(self, rule: Rule) -> TripleIntegral<E, F1, F2, F3, F4, Rule, Rule, Rule> {
+        self.with_x_rule(rule).with_y_rule(rule).with_z_rule(rule)
+    }
+
+    pub fn with_x_rule(self, rule: Rule) -> TripleIntegral<E, F1, F2, F3, F4, Rule, Y, Z> {
+        TripleIntegral::new(
+            self.equation,
+            self.first_integral_begin,
+            self.first_integral_end,
+            self.second_integral_begin,
+            self.second_integral_end,
+            self.third_integral_begin,
+            self.third_integral_end,
+            (rule, self.y_rule, self.z_rule),
+        )
+    }
+
+    pub fn with_y_rule(self, rule: Rule) -> TripleIntegral<E, F1, F2, F3, F4, X, Rule, Z> {
+        TripleIntegral::new(
+            self.equation,
+            self.first_integral_begin,
+            self.first_integral_end,
+            self.second_integral_begin,
+            self.second_integral_end,
+            self.third_integral_begin,
+            self.third_integral_end,
+            (self.x_rule, rule, self.z_rule),
+        )
+    }
+
+    pub fn with_z_rule(self, rule: Rule) -> TripleIntegral<E, F1, F2, F3, F4, X, Y, Rule> {
+        TripleIntegral::new(
+            self.equation,
+            self.first_integral_begin,
+            self.first_integral_end,
+            self.second_integral_begin,
+            self.second_integral_end,
+            self.third_integral_begin,
+            self.third_integral_end,
+            (self.x_rule, self.y_rule, rule),
+        )
+    }
+}
+
+impl<
+        E: Fn(f64, f64, f64) -> f64,
+        F1: Fn(f64) -> f64,
+        F2: Fn(f64) -> f64,
+        F3: Fn(f64, f64) -> f64,
+        F4: Fn(f64, f64) -> f64,
+    > TripleIntegral<E, F1, F2, F3, F4, Rule, Rule, Rule>
+{
+    #[throws]
+    pub fn compute(self) -> f64 {
+        engine::calculate_triple_integral_with_rules(
+            DimensionQuadrature::new(self.x_rule)?,
+            DimensionQuadrature::new(self.y_rule)?,
+            DimensionQuadrature::new(self.z_rule)?,
+            self.equation,
+            self.first_integral_begin,
+            self.first_integral_end,
+            self.second_integral_begin,
+            self.second_integral_end,
+            self.third_integral_begin,
+            self.third_integral_end,
+        )?
+    }
+}
@@ -1,4 +1,15 @@
 mod calculation_functions;
 pub use calculation_functions::*;
-mod engine;
+pub mod engine;
+mod integral;
+pub use engine::{
+    function_types::Fallible,
+    quadrature::{
+        gauss_kronrod::GaussKronrodRule, monte_carlo::MonteCarloSampling,
+        newton_cotes::NewtonCotesRule,
+    },
+    DimensionBounds, IntegrationOutcome, NonFinitePolicy, Resolution,
+};
+pub use integral::{DoubleIntegral, Integral, NoRule, Rule, SingleIntegral, TripleIntegral};
 pub mod errors;
+pub mod prelude;
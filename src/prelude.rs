@@ -0,0 +1,29 @@
+#[cfg(feature = "parallel")]
+pub use crate::engine::{
+    calculate_double_integral_parallel, calculate_single_integral_parallel,
+    calculate_triple_integral_parallel,
+};
+pub use crate::{
+    engine::{
+        calculate_double_integral, calculate_double_integral_with_rules,
+        calculate_multiple_integral_with_rule, calculate_single_integral,
+        calculate_single_integral_with_rule, calculate_triple_integral,
+        calculate_triple_integral_with_rules,
+        function_types::{
+            Fallible, OneVariableFunction, ThreeVariableFunction, TwoVariableFunction,
+        },
+        helper_equation_traits::{
+            EquationOfOneVariable, EquationOfThreeVariable, EquationOfTwoVariable,
+        },
+        quadrature::{
+            FinalizeCalculation, GetConvergenceOrder, GetQuadratureRange,
+            GetResolutionDoubleIntegral, GetResolutionSingleIntegral, GetResolutionTripleIntegral,
+            QuadratureDoubleIntegral, QuadratureRule, QuadratureSingleIntegral,
+            QuadratureTripleIntegral,
+        },
+        range_generator::RangeGenerator,
+        Bounds, CalculationResult, CalculationStep, DimensionBounds, IndexRangeGenerator,
+        IntegrationOutcome, NonFinitePolicy, Resolution, StepValidator, Tolerance,
+    },
+    errors::{Error, IntegrandError, Result},
+};
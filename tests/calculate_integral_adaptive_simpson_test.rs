@@ -0,0 +1,183 @@
+use assert_approx_eq::assert_approx_eq;
+use sequential_integration::{
+    calculate_double_integral_adaptive_simpson, calculate_single_integral_adaptive_simpson,
+    calculate_triple_integral_adaptive_simpson, errors::Error,
+};
+
+#[test]
+fn calculate_single_integral_adaptive_simpson_not_const_equation() {
+    let equation = |x: f64| (1. - x.powf(2.)).sqrt();
+
+    let result = calculate_single_integral_adaptive_simpson(equation, -1., 1., 1e-8, 0.).unwrap();
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        std::f64::consts::FRAC_PI_2,
+        result - std::f64::consts::FRAC_PI_2
+    );
+
+    assert_approx_eq!(result, std::f64::consts::FRAC_PI_2, 1e-6);
+}
+
+#[test]
+fn calculate_single_integral_adaptive_simpson_reversed_bounds() {
+    let equation = |x: f64| x.exp();
+
+    let result = calculate_single_integral_adaptive_simpson(equation, 1., 0., 0., 1e-10).unwrap();
+
+    assert_approx_eq!(result, 1. - std::f64::consts::E, 1e-8);
+}
+
+#[test]
+fn calculate_single_integral_adaptive_simpson_vanishing_first_estimate() {
+    // every node of the first Simpson estimate is a zero of the equation
+    let equation = |x: f64| x.sin().powf(2.);
+
+    let result = calculate_single_integral_adaptive_simpson(
+        equation,
+        0.,
+        2. * std::f64::consts::PI,
+        0.,
+        1e-8,
+    )
+    .unwrap();
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        std::f64::consts::PI,
+        result - std::f64::consts::PI
+    );
+
+    assert_approx_eq!(result, std::f64::consts::PI, 1e-7);
+}
+
+#[test]
+fn calculate_single_integral_adaptive_simpson_invalid_tolerance() {
+    let equation = |x: f64| x;
+
+    assert!(calculate_single_integral_adaptive_simpson(equation, 0., 1., 0., 0.).is_err());
+    assert!(calculate_single_integral_adaptive_simpson(equation, 0., 1., -1e-6, 0.).is_err());
+    assert!(calculate_single_integral_adaptive_simpson(equation, 0., 1., f64::NAN, 0.).is_err());
+}
+
+#[test]
+fn calculate_single_integral_adaptive_simpson_non_finite_estimate() {
+    let equation = |x: f64| {
+        if x > 0.3 && x < 0.4 {
+            f64::NAN
+        } else {
+            x.exp()
+        }
+    };
+
+    match calculate_single_integral_adaptive_simpson(equation, 0., 1., 1e-8, 0.) {
+        Err(Error::NonFiniteEstimate { begin, end, .. }) => {
+            assert!(begin <= 0.4 && end >= 0.3 && begin < end)
+        }
+        result => panic!("expected NonFiniteEstimate, got {:?}", result),
+    }
+}
+
+#[test]
+fn calculate_double_integral_adaptive_simpson_circle() {
+    let equation = |_x, _y| 1.;
+    let second_integral_begin = |_x| -0.;
+    let second_integral_end = |x: f64| (1. - x.powf(2.)).sqrt();
+
+    let result = calculate_double_integral_adaptive_simpson(
+        equation,
+        -1.,
+        1.,
+        second_integral_begin,
+        second_integral_end,
+        1e-8,
+        0.,
+    )
+    .unwrap();
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        std::f64::consts::FRAC_PI_2,
+        result - std::f64::consts::FRAC_PI_2
+    );
+
+    assert_approx_eq!(result, std::f64::consts::FRAC_PI_2, 1e-6);
+}
+
+#[test]
+fn calculate_double_integral_adaptive_simpson_not_const_equation() {
+    let equation = |x: f64, y: f64| x.powf(2.) / y.powf(2.);
+    let second_integral_begin = |x: f64| 1. / x;
+    let second_integral_end = |x: f64| x;
+
+    let result = calculate_double_integral_adaptive_simpson(
+        equation,
+        1.,
+        2.,
+        second_integral_begin,
+        second_integral_end,
+        1e-10,
+        1e-10,
+    )
+    .unwrap();
+
+    assert_approx_eq!(result, 2.25, 1e-8);
+}
+
+#[test]
+fn calculate_triple_integral_adaptive_simpson_sphere() {
+    let equation = |_x, _y, _z| 1.;
+    let second_integral_begin = |_x| 0.;
+    let second_integral_end = |x: f64| (1. - x.powf(2.)).sqrt();
+    let third_integral_begin = |_x, _y| 0.;
+    let third_integral_end = |x: f64, y: f64| (1. - x.powf(2.) - y.powf(2.)).max(0.).sqrt();
+
+    let result = calculate_triple_integral_adaptive_simpson(
+        equation,
+        -1.,
+        1.,
+        second_integral_begin,
+        second_integral_end,
+        third_integral_begin,
+        third_integral_end,
+        1e-6,
+        0.,
+    )
+    .unwrap();
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        std::f64::consts::FRAC_PI_3,
+        result - std::f64::consts::FRAC_PI_3
+    );
+
+    assert_approx_eq!(result, std::f64::consts::FRAC_PI_3, 1e-4);
+}
+
+#[test]
+fn calculate_triple_integral_adaptive_simpson_not_const_equation() {
+    let equation = |x: f64, y: f64, z: f64| x.powf(2.) + y.powf(2.) + z.powf(2.);
+    let second_integral_begin = |_x| 0.;
+    let second_integral_end = |x: f64| x;
+    let third_integral_begin = |_x, _y| 0.;
+    let third_integral_end = |x: f64, y: f64| x + y;
+
+    let result = calculate_triple_integral_adaptive_simpson(
+        equation,
+        0.,
+        1.,
+        second_integral_begin,
+        second_integral_end,
+        third_integral_begin,
+        third_integral_end,
+        1e-10,
+        0.,
+    )
+    .unwrap();
+
+    assert_approx_eq!(result, 2. / 3., 1e-8);
+}
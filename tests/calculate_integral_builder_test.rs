@@ -0,0 +1,135 @@
+use assert_approx_eq::assert_approx_eq;
+use sequential_integration::{
+    calculate_double_integral_simpson, calculate_triple_integral_gauss_legendre, GaussKronrodRule,
+    Integral, Resolution, Rule,
+};
+
+#[test]
+fn calculate_single_integral_builder_simpson() {
+    let result = Integral::new(|x: f64| (1. - x.powf(2.)).sqrt())
+        .over_x(-1., 1.)
+        .with_rule(Rule::simpson(0.001))
+        .compute()
+        .unwrap();
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        std::f64::consts::FRAC_PI_2,
+        result - std::f64::consts::FRAC_PI_2
+    );
+
+    assert_approx_eq!(result, std::f64::consts::FRAC_PI_2, 1e-4);
+}
+
+#[test]
+fn calculate_double_integral_builder_same_as_function() {
+    let equation = |x: f64, y: f64| x.powf(2.) / y.powf(2.);
+    let second_integral_begin = |x: f64| 1. / x;
+    let second_integral_end = |x: f64| x;
+
+    let result = Integral::new(equation)
+        .over_x(1., 2.)
+        .over_y(second_integral_begin, second_integral_end)
+        .with_rule(Rule::simpson(0.01))
+        .compute()
+        .unwrap();
+    let expected = calculate_double_integral_simpson(
+        equation,
+        1.,
+        2.,
+        0.01,
+        second_integral_begin,
+        second_integral_end,
+        0.01,
+    )
+    .unwrap();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn calculate_double_integral_builder_rule_per_dimension() {
+    let result = Integral::new(|_x, _y| 1.)
+        .over_x(-1., 1.)
+        .with_x_rule(Rule::gauss_legendre(Resolution::Panels(4), 10))
+        .over_y(|_x| 0., |x: f64| (1. - x.powf(2.)).sqrt())
+        .with_y_rule(Rule::GaussKronrod {
+            rule: GaussKronrodRule::G7K15,
+            abs_tol: 1e-10,
+            rel_tol: 0.,
+            limit: 50,
+        })
+        .compute()
+        .unwrap();
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        std::f64::consts::FRAC_PI_2,
+        result - std::f64::consts::FRAC_PI_2
+    );
+
+    assert_approx_eq!(result, std::f64::consts::FRAC_PI_2, 1e-3);
+}
+
+#[test]
+fn calculate_double_integral_builder_same_gauss_legendre_rules() {
+    for y_points in [5, 6].iter() {
+        let result = Integral::new(|_x, _y| 1.)
+            .over_x(0., 1.)
+            .with_x_rule(Rule::gauss_legendre(Resolution::Panels(1), 5))
+            .over_y(|_x| 0., |x| x)
+            .with_y_rule(Rule::gauss_legendre(Resolution::Panels(1), *y_points))
+            .compute()
+            .unwrap();
+
+        assert_approx_eq!(result, 0.5, 1e-15);
+    }
+}
+
+#[test]
+fn calculate_triple_integral_builder_same_as_function() {
+    let equation = |x: f64, y: f64, z: f64| x * y * z;
+
+    let result = Integral::new(equation)
+        .over_x(0., 1.)
+        .over_y(|_x| 0., |x| x)
+        .over_z(|_x, _y| 0., |x, y| x + y)
+        .with_rule(Rule::gauss_legendre(0.1, 3))
+        .compute()
+        .unwrap();
+    let expected = calculate_triple_integral_gauss_legendre(
+        equation,
+        0.,
+        1.,
+        0.1,
+        |_x| 0.,
+        |x| x,
+        0.1,
+        |_x, _y| 0.,
+        |x, y| x + y,
+        0.1,
+        3,
+    )
+    .unwrap();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn calculate_triple_integral_builder_mixed_rules() {
+    let result = Integral::new(|x: f64, y: f64, z: f64| x.powf(2.) + y.powf(2.) + z.powf(2.))
+        .over_x(0., 1.)
+        .over_y(|_x| 0., |x| x)
+        .over_z(|_x, _y| 0., |x, y| x + y)
+        .with_rule(Rule::AdaptiveSimpson {
+            abs_tol: 1e-10,
+            rel_tol: 0.,
+        })
+        .with_z_rule(Rule::simpson(Resolution::Panels(2)))
+        .compute()
+        .unwrap();
+
+    assert_approx_eq!(result, 2. / 3., 1e-8);
+}
@@ -0,0 +1,128 @@
+use assert_approx_eq::assert_approx_eq;
+use sequential_integration::{
+    calculate_double_integral_clenshaw_curtis, calculate_single_integral_clenshaw_curtis,
+    calculate_triple_integral_clenshaw_curtis, errors::Error, Integral, Rule,
+};
+use std::cell::Cell;
+
+#[test]
+fn calculate_single_integral_clenshaw_curtis_smooth() {
+    let equation = |x: f64| 1. / (1. + x.powi(2));
+
+    let result =
+        calculate_single_integral_clenshaw_curtis(equation, -1., 1., 1e-13, 0., 12).unwrap();
+    let expected = std::f64::consts::FRAC_PI_2;
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        expected,
+        result - expected
+    );
+    assert_approx_eq!(result, expected, 1e-13);
+}
+
+#[test]
+fn calculate_single_integral_clenshaw_curtis_reuses_nodes() {
+    let evaluations = Cell::new(0usize);
+    let equation = |x: f64| {
+        evaluations.set(evaluations.get() + 1);
+        x.exp()
+    };
+
+    let result =
+        calculate_single_integral_clenshaw_curtis(equation, 0., 1., 1e-12, 0., 12).unwrap();
+
+    println!("evaluations: {}", evaluations.get());
+    assert_approx_eq!(result, 1f64.exp() - 1., 1e-12);
+    assert!((evaluations.get() - 1).is_power_of_two());
+    assert!(evaluations.get() <= 33);
+}
+
+#[test]
+fn calculate_single_integral_clenshaw_curtis_polynomial_reversed() {
+    let equation = |x: f64| x.powi(7) - x.powi(2);
+
+    let result =
+        calculate_single_integral_clenshaw_curtis(equation, 2., 0., 1e-12, 0., 12).unwrap();
+
+    assert_approx_eq!(result, -(256. / 8. - 8. / 3.), 1e-11);
+}
+
+#[test]
+fn calculate_double_integral_clenshaw_curtis_triangle() {
+    let equation = |x: f64, y: f64| (x * y).sin();
+
+    let result =
+        calculate_double_integral_clenshaw_curtis(equation, 0., 1., |_x| 0., |x| x, 1e-12, 0., 12)
+            .unwrap();
+    // ∫ (1 - cos(x²)) / x dx over [0, 1] = (γ - Ci(1)) / 2
+    let expected = 0.119905871000282;
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        expected,
+        result - expected
+    );
+    assert_approx_eq!(result, expected, 1e-10);
+}
+
+#[test]
+fn calculate_triple_integral_clenshaw_curtis_cube() {
+    let equation = |x: f64, y: f64, z: f64| (x + y + z).exp();
+
+    let result = calculate_triple_integral_clenshaw_curtis(
+        equation,
+        0.,
+        1.,
+        |_x| 0.,
+        |_x| 1.,
+        |_x, _y| 0.,
+        |_x, _y| 1.,
+        1e-11,
+        0.,
+        12,
+    )
+    .unwrap();
+
+    assert_approx_eq!(result, (1f64.exp() - 1.).powi(3), 1e-9);
+}
+
+#[test]
+fn calculate_double_integral_builder_clenshaw_curtis_rule() {
+    let result = Integral::new(|x: f64, y: f64| x.powf(2.) * y)
+        .over_x(0., 1.)
+        .over_y(|_x| 0., |x| x)
+        .with_rule(Rule::ClenshawCurtis {
+            abs_tol: 1e-12,
+            rel_tol: 0.,
+            max_level: 12,
+        })
+        .compute()
+        .unwrap();
+
+    assert_approx_eq!(result, 0.1, 1e-12);
+}
+
+#[test]
+fn calculate_single_integral_clenshaw_curtis_limits() {
+    let equation = |x: f64| x.abs().sqrt();
+
+    match calculate_single_integral_clenshaw_curtis(equation, -1., 1., 1e-14, 0., 4) {
+        Err(Error::LevelLimitReached { limit: 4, .. }) => {}
+        result => panic!("expected LevelLimitReached, got {:?}", result),
+    }
+
+    for max_level in [0, 2, 13, 40].iter() {
+        match calculate_single_integral_clenshaw_curtis(equation, -1., 1., 1e-14, 0., *max_level) {
+            Err(Error::InvalidLevel {
+                level,
+                min: 3,
+                max: 12,
+                ..
+            }) => assert_eq!(level, *max_level),
+            result => panic!("expected InvalidLevel for {}, got {:?}", max_level, result),
+        }
+    }
+}
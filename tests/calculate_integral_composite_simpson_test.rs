@@ -0,0 +1,117 @@
+use assert_approx_eq::assert_approx_eq;
+use sequential_integration::{
+    calculate_double_integral_simpson_with_report, calculate_single_integral_simpson,
+    calculate_single_integral_simpson_with_report, calculate_triple_integral_simpson,
+    calculate_triple_integral_simpson_with_report,
+    engine::{self, quadrature::simpson::CompositeSimpsonQuadrature},
+};
+
+#[test]
+fn calculate_single_integral_simpson_with_report_evaluations() {
+    let equation = |x: f64| x.sin();
+
+    let outcome = calculate_single_integral_simpson_with_report(equation, 0., 1., 0.0625).unwrap();
+    let expected = 1. - 1f64.cos();
+
+    assert!((outcome.value - expected).abs() <= 2. * outcome.error);
+    assert_eq!(outcome.panels, 8);
+    assert_eq!(outcome.evaluations, 17 + 9);
+}
+
+#[test]
+fn calculate_double_integral_simpson_with_report_evaluations() {
+    let equation = |x: f64, y: f64| (x * y).exp();
+
+    let outcome = calculate_double_integral_simpson_with_report(
+        equation,
+        0.,
+        1.,
+        0.125,
+        |_x| 0.,
+        |_x| 2.,
+        0.125,
+    )
+    .unwrap();
+    let expected = 3.68387151054041;
+
+    println!(
+        "outcome: {:?}, expected: {}, diff: {}",
+        outcome,
+        expected,
+        outcome.value - expected
+    );
+
+    assert_approx_eq!(outcome.value, expected, 1e-4);
+    assert!((outcome.value - expected).abs() <= 2. * outcome.error);
+    assert_eq!(outcome.evaluations, 9 * 17 + 5 * 9);
+}
+
+#[test]
+fn calculate_triple_integral_simpson_with_report_evaluations() {
+    let equation = |x: f64, y: f64, z: f64| x * y * z;
+
+    let outcome = calculate_triple_integral_simpson_with_report(
+        equation,
+        0.,
+        1.,
+        0.25,
+        |_x| 0.,
+        |_x| 1.,
+        0.25,
+        |_x, _y| 0.,
+        |_x, _y| 1.,
+        0.25,
+    )
+    .unwrap();
+
+    assert_approx_eq!(outcome.value, 0.125, 1e-12);
+    // a 3×3×3 stencil per cell would take 8 * 27 + 27 evaluations
+    assert_eq!(outcome.evaluations, 5 * 5 * 5 + 3 * 3 * 3);
+}
+
+#[test]
+fn calculate_single_integral_simpson_counts_reset() {
+    let equation = |x: f64| x.sin();
+    let quadrature = CompositeSimpsonQuadrature::new(0.0625.into()).unwrap();
+
+    for _ in 0..2 {
+        let result =
+            engine::calculate_single_integral_with_rule(&quadrature, equation, 0., 1.).unwrap();
+
+        assert_approx_eq!(
+            result,
+            calculate_single_integral_simpson(equation, 0., 1., 0.0625).unwrap(),
+            1e-15
+        );
+        assert_eq!(quadrature.evaluations(), 17);
+        assert_eq!(quadrature.panels(), 8);
+    }
+}
+
+#[test]
+fn calculate_triple_integral_simpson_not_const_equation() {
+    let equation = |x: f64, y: f64, z: f64| x.powf(2.) + y.powf(2.) + z.powf(2.);
+
+    let result = calculate_triple_integral_simpson(
+        equation,
+        0.,
+        1.,
+        0.01,
+        |_x| 0.,
+        |x| x,
+        0.01,
+        |_x, _y| 0.,
+        |x, y| x + y,
+        0.01,
+    )
+    .unwrap();
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        2. / 3.,
+        result - 2. / 3.
+    );
+
+    assert_approx_eq!(result, 2. / 3., 1e-8);
+}
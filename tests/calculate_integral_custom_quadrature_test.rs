@@ -0,0 +1,164 @@
+use assert_approx_eq::assert_approx_eq;
+use sequential_integration::{errors, prelude::*};
+use snafu::ResultExt;
+
+struct MidpointQuadratureDoubleIntegral<E: Fn(f64, f64) -> f64> {
+    equation: E,
+    h: Resolution,
+    k: Resolution,
+}
+
+impl<E: Fn(f64, f64) -> f64> MidpointQuadratureDoubleIntegral<E> {
+    fn panel_end(step: CalculationStep, bounds: Bounds, resolution: Resolution) -> f64 {
+        if step.is_last() {
+            bounds.end
+        } else {
+            *step + resolution.panel_width(bounds, 1.)
+        }
+    }
+}
+
+impl<E: Fn(f64, f64) -> f64> EquationOfTwoVariable for MidpointQuadratureDoubleIntegral<E> {
+    fn calculate(
+        &self,
+        x: CalculationStep,
+        bounds_x: Bounds,
+        y: CalculationStep,
+        bounds_y: Bounds,
+    ) -> Result<CalculationResult> {
+        let x_end = Self::panel_end(x, bounds_x, self.h);
+        let y_end = Self::panel_end(y, bounds_y, self.k);
+
+        let mut result = CalculationResult::new();
+        result.count_panel(1);
+        result.add_last(
+            (self.equation)((*x + x_end) / 2., (*y + y_end) / 2.) * (x_end - *x) * (y_end - *y),
+        );
+
+        Ok(result)
+    }
+}
+
+impl<E: Fn(f64, f64) -> f64> FinalizeCalculation for MidpointQuadratureDoubleIntegral<E> {
+    fn finalize(&self, result: CalculationResult) -> Result<f64> {
+        Ok(result.common + result.last)
+    }
+}
+
+impl<E: Fn(f64, f64) -> f64> GetResolutionDoubleIntegral for MidpointQuadratureDoubleIntegral<E> {
+    fn get_resolution(&self) -> (Resolution, Resolution) {
+        (self.h, self.k)
+    }
+}
+
+impl<E: Fn(f64, f64) -> f64> GetQuadratureRange for MidpointQuadratureDoubleIntegral<E> {
+    type Range = IndexRangeGenerator;
+
+    fn get_range_generator(bounds: Bounds, resolution: Resolution) -> Result<Option<Self::Range>> {
+        IndexRangeGenerator::new(bounds, resolution.panel_width(bounds, 1.))
+    }
+}
+
+impl<E: Fn(f64, f64) -> f64> QuadratureDoubleIntegral for MidpointQuadratureDoubleIntegral<E> {}
+
+struct TwoPointGaussRule;
+
+impl QuadratureRule for TwoPointGaussRule {
+    fn integrate<E: Fn(f64) -> Result<f64>>(&self, equation: E, bounds: Bounds) -> Result<f64> {
+        let center = (bounds.begin + bounds.end) / 2.;
+        let half_width = (bounds.end - bounds.begin) / 2.;
+        let offset = half_width / 3f64.sqrt();
+
+        Ok(half_width * (equation(center - offset)? + equation(center + offset)?))
+    }
+}
+
+struct PanelRule {
+    panels: usize,
+}
+
+impl QuadratureRule for PanelRule {
+    fn integrate<E: Fn(f64) -> Result<f64>>(&self, equation: E, bounds: Bounds) -> Result<f64> {
+        if self.panels == 0 {
+            return errors::ZeroPanelCount.fail();
+        }
+        if self.panels > 1000 {
+            return Err(IntegrandError::from("too many panels")).context(errors::Integrand);
+        }
+
+        let width = (bounds.end - bounds.begin) / self.panels as f64;
+        let mut result = 0.;
+        for i in 0..self.panels {
+            result += width * equation(bounds.begin + (i as f64 + 0.5) * width)?;
+        }
+
+        Ok(result)
+    }
+}
+
+#[test]
+fn calculate_double_integral_custom_quadrature() {
+    let quadrature = MidpointQuadratureDoubleIntegral {
+        equation: |x: f64, y: f64| x * y,
+        h: Resolution::Panels(4),
+        k: Resolution::Panels(4),
+    };
+
+    let result = calculate_double_integral(quadrature, 0., 1., |_x| 0., |_x| 1.).unwrap();
+
+    assert_approx_eq!(result, 0.25, 1e-12);
+}
+
+#[test]
+fn calculate_double_integral_custom_quadrature_variable_bounds() {
+    let quadrature = MidpointQuadratureDoubleIntegral {
+        equation: |_x, y: f64| y,
+        h: Resolution::Step(0.001),
+        k: Resolution::Panels(10),
+    };
+
+    let result = calculate_double_integral(quadrature, 0., 1., |x| x, |x| x + 1.).unwrap();
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        1.,
+        result - 1.
+    );
+
+    assert_approx_eq!(result, 1., 1e-2);
+}
+
+#[test]
+fn calculate_double_integral_custom_rule() {
+    let result = calculate_double_integral_with_rules(
+        TwoPointGaussRule,
+        TwoPointGaussRule,
+        |x: f64, y: f64| x * y,
+        1.,
+        0.,
+        |_x| 0.,
+        |x| x,
+    )
+    .unwrap();
+
+    assert_approx_eq!(result, -1. / 8., 1e-12);
+}
+
+#[test]
+fn calculate_single_integral_custom_rule_errors() {
+    let equation = |x: f64| x;
+
+    let result = calculate_single_integral_with_rule(PanelRule { panels: 4 }, equation, 0., 1.);
+    assert_approx_eq!(result.unwrap(), 0.5, 1e-15);
+
+    match calculate_single_integral_with_rule(PanelRule { panels: 0 }, equation, 0., 1.) {
+        Err(Error::ZeroPanelCount { .. }) => {}
+        result => panic!("expected ZeroPanelCount, got {:?}", result),
+    }
+
+    match calculate_single_integral_with_rule(PanelRule { panels: 1001 }, equation, 0., 1.) {
+        Err(Error::Integrand { source, .. }) => assert_eq!(source.to_string(), "too many panels"),
+        result => panic!("expected Integrand, got {:?}", result),
+    }
+}
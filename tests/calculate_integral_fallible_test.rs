@@ -0,0 +1,121 @@
+use assert_approx_eq::assert_approx_eq;
+use sequential_integration::{
+    calculate_double_integral_simpson_fallible, calculate_single_integral_simpson,
+    calculate_single_integral_simpson_fallible, calculate_triple_integral_simpson_fallible,
+    engine::{self, quadrature::gauss_kronrod::GaussKronrodQuadrature},
+    errors::Error,
+    Fallible, GaussKronrodRule,
+};
+use std::cell::Cell;
+
+#[derive(Debug)]
+struct LookupError {
+    x: f64,
+}
+
+impl std::fmt::Display for LookupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "no table entry for {}", self.x)
+    }
+}
+
+impl std::error::Error for LookupError {}
+
+#[test]
+fn calculate_single_integral_simpson_fallible_same_value() {
+    let equation = |x: f64| (1. - x.powf(2.)).sqrt();
+
+    let value = calculate_single_integral_simpson(equation, -1., 1., 0.01).unwrap();
+    let fallible_value = calculate_single_integral_simpson_fallible(
+        |x| Ok::<_, LookupError>(equation(x)),
+        -1.,
+        1.,
+        0.01,
+    )
+    .unwrap();
+
+    assert_eq!(value, fallible_value);
+}
+
+#[test]
+fn calculate_single_integral_simpson_fallible_error() {
+    let equation = |x: f64| {
+        if x > 0.5 {
+            Err(LookupError { x })
+        } else {
+            Ok(x)
+        }
+    };
+
+    match calculate_single_integral_simpson_fallible(equation, 0., 1., 0.25) {
+        Err(Error::Integrand { source, .. }) => {
+            let error = source.downcast_ref::<LookupError>().unwrap();
+            assert_approx_eq!(error.x, 0.75);
+        }
+        result => panic!("unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn calculate_double_integral_simpson_fallible_stops_at_first_error() {
+    let calls = Cell::new(0);
+    let equation = |x: f64, y: f64| {
+        calls.set(calls.get() + 1);
+        if x >= 0.5 {
+            Err(LookupError { x })
+        } else {
+            Ok(x * y)
+        }
+    };
+
+    let result =
+        calculate_double_integral_simpson_fallible(equation, 0., 1., 0.1, |_x| 0., |_x| 1., 0.1);
+
+    assert!(matches!(result, Err(Error::Integrand { .. })));
+    assert!(calls.get() < 5 * 5 * 9);
+}
+
+#[test]
+fn calculate_triple_integral_simpson_fallible_not_const_equation() {
+    let equation = |x: f64, y: f64, z: f64| Ok::<_, LookupError>(x * y * z);
+
+    let result = calculate_triple_integral_simpson_fallible(
+        equation,
+        0.,
+        1.,
+        0.25,
+        |_x| 0.,
+        |_x| 1.,
+        0.25,
+        |_x, _y| 0.,
+        |_x, _y| 1.,
+        0.25,
+    )
+    .unwrap();
+
+    assert_approx_eq!(result, 0.125, 1e-12);
+}
+
+#[test]
+fn calculate_double_integral_with_rules_fallible() {
+    let quadrature = GaussKronrodQuadrature::new(GaussKronrodRule::G7K15, 1e-10, 0., 50).unwrap();
+    let equation = |x: f64, y: f64| {
+        if y > 0.9 {
+            Err(LookupError { x: y })
+        } else {
+            Ok(x + y)
+        }
+    };
+
+    let result = engine::calculate_double_integral_with_rules(
+        quadrature,
+        quadrature,
+        Fallible(equation),
+        0.,
+        1.,
+        |_x| 0.,
+        |_x| 1.,
+    );
+
+    assert!(matches!(result, Err(Error::Integrand { .. })));
+}
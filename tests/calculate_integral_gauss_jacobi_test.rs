@@ -0,0 +1,173 @@
+use std::f64::consts::PI;
+
+use assert_approx_eq::assert_approx_eq;
+use sequential_integration::{
+    calculate_double_integral_gauss_jacobi, calculate_single_integral_gauss_jacobi,
+    engine::quadrature::{gauss_jacobi::GaussJacobiQuadrature, gauss_kronrod::GaussKronrodRule},
+    errors::Error,
+    Integral, Rule,
+};
+
+#[test]
+fn calculate_single_integral_gauss_jacobi_legendre_weight() {
+    let equation = |x: f64| x.powi(4) - x;
+
+    let result = calculate_single_integral_gauss_jacobi(equation, -1., 1., 3, 0., 0.).unwrap();
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        0.4,
+        result - 0.4
+    );
+    assert_approx_eq!(result, 0.4, 1e-14);
+}
+
+#[test]
+fn calculate_single_integral_gauss_jacobi_chebyshev_weight() {
+    let result = calculate_single_integral_gauss_jacobi(|_x| 1., -1., 1., 5, -0.5, -0.5).unwrap();
+    assert_approx_eq!(result, PI, 1e-13);
+
+    let result =
+        calculate_single_integral_gauss_jacobi(|x: f64| x.powi(2), -1., 1., 5, -0.5, -0.5).unwrap();
+    assert_approx_eq!(result, PI / 2., 1e-13);
+}
+
+#[test]
+fn calculate_single_integral_gauss_jacobi_mapped_interval() {
+    // ∫ √(2 - x) / √x dx over [0, 2]
+    let result = calculate_single_integral_gauss_jacobi(|_x| 1., 0., 2., 4, 0.5, -0.5).unwrap();
+    assert_approx_eq!(result, PI, 1e-13);
+
+    // ∫ √x √(2 - x) dx over [0, 2]
+    let result = calculate_single_integral_gauss_jacobi(|_x| 1., 0., 2., 4, 0.5, 0.5).unwrap();
+    assert_approx_eq!(result, PI / 2., 1e-13);
+
+    let result = calculate_single_integral_gauss_jacobi(|_x| 1., 2., 0., 4, 0.5, 0.5).unwrap();
+    assert_approx_eq!(result, -PI / 2., 1e-13);
+}
+
+#[test]
+fn calculate_single_integral_gauss_jacobi_endpoint_singularity() {
+    // ∫ cos(x) / √x dx over [0, 1]
+    let result =
+        calculate_single_integral_gauss_jacobi(|x: f64| x.cos(), 0., 1., 10, 0., -0.5).unwrap();
+    let expected = 1.8090484758005438;
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        expected,
+        result - expected
+    );
+    assert_approx_eq!(result, expected, 1e-13);
+}
+
+#[test]
+fn calculate_double_integral_gauss_jacobi_triangle() {
+    let outer_rule = Rule::GaussKronrod {
+        rule: GaussKronrodRule::G10K21,
+        abs_tol: 1e-12,
+        rel_tol: 0.,
+        limit: 50,
+    };
+
+    let result = calculate_double_integral_gauss_jacobi(
+        |x: f64, y: f64| x * y,
+        0.,
+        1.,
+        |_x| 0.,
+        |x| x,
+        outer_rule,
+        3,
+        0.,
+        0.,
+    )
+    .unwrap();
+    assert_approx_eq!(result, 0.125, 1e-14);
+
+    // ∫∫ 1 / √(x - y) dy dx over 0 ≤ y ≤ x ≤ 1, the outer variable is not weighted
+    let result = calculate_double_integral_gauss_jacobi(
+        |_x, _y| 1.,
+        0.,
+        1.,
+        |_x| 0.,
+        |x| x,
+        outer_rule,
+        6,
+        -0.5,
+        0.,
+    )
+    .unwrap();
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        4. / 3.,
+        result - 4. / 3.
+    );
+    assert_approx_eq!(result, 4. / 3., 1e-10);
+}
+
+#[test]
+fn calculate_double_integral_builder_gauss_jacobi_inner_rule() {
+    // ∫∫ y / √(x - y) dy dx over 0 ≤ y ≤ x ≤ 1
+    let result = Integral::new(|_x: f64, y: f64| y)
+        .over_x(0., 1.)
+        .over_y(|_x| 0., |x| x)
+        .with_x_rule(Rule::GaussKronrod {
+            rule: GaussKronrodRule::G10K21,
+            abs_tol: 1e-12,
+            rel_tol: 0.,
+            limit: 50,
+        })
+        .with_y_rule(Rule::GaussJacobi {
+            points: 4,
+            alpha: -0.5,
+            beta: 0.,
+        })
+        .compute()
+        .unwrap();
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        8. / 15.,
+        result - 8. / 15.
+    );
+    assert_approx_eq!(result, 8. / 15., 1e-10);
+}
+
+#[test]
+fn gauss_jacobi_quadrature_many_points() {
+    let quadrature = GaussJacobiQuadrature::new(100, 0., 0.).unwrap();
+
+    let sum: f64 = quadrature.weights().iter().sum();
+    assert_approx_eq!(sum, 2., 1e-12);
+    assert!(quadrature.nodes().windows(2).all(|pair| pair[0] > pair[1]));
+
+    // 2⁶ B(3, 4)
+    let quadrature = GaussJacobiQuadrature::new(100, 2., 3.).unwrap();
+
+    let sum: f64 = quadrature.weights().iter().sum();
+    assert_approx_eq!(sum, 64. / 60., 1e-12);
+    assert!(quadrature.nodes().iter().all(|node| node.abs() < 1.));
+}
+
+#[test]
+fn calculate_single_integral_gauss_jacobi_invalid_parameters() {
+    match calculate_single_integral_gauss_jacobi(|x| x, 0., 1., 5, -1., 0.) {
+        Err(Error::InvalidWeightExponent { .. }) => {}
+        result => panic!("expected InvalidWeightExponent, got {:?}", result),
+    }
+
+    match calculate_single_integral_gauss_jacobi(|x| x, 0., 1., 5, 0., -1.5) {
+        Err(Error::InvalidWeightExponent { .. }) => {}
+        result => panic!("expected InvalidWeightExponent, got {:?}", result),
+    }
+
+    match calculate_single_integral_gauss_jacobi(|x| x, 0., 1., 0, 0., 0.) {
+        Err(Error::InvalidPointCount { .. }) => {}
+        result => panic!("expected InvalidPointCount, got {:?}", result),
+    }
+}
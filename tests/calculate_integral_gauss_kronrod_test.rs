@@ -0,0 +1,172 @@
+use assert_approx_eq::assert_approx_eq;
+use sequential_integration::{
+    calculate_double_integral_gauss_kronrod, calculate_single_integral_gauss_kronrod,
+    calculate_triple_integral_gauss_kronrod, errors::Error, GaussKronrodRule,
+};
+
+#[test]
+fn calculate_single_integral_gauss_kronrod_polynomial() {
+    let equation = |x: f64| x.powi(20) - 3. * x.powi(5);
+
+    for rule in [GaussKronrodRule::G7K15, GaussKronrodRule::G10K21].iter() {
+        let result =
+            calculate_single_integral_gauss_kronrod(equation, 0., 1., 1e-13, 0., *rule, 50)
+                .unwrap();
+
+        assert_approx_eq!(result, 1. / 21. - 0.5, 1e-13);
+    }
+}
+
+#[test]
+fn calculate_single_integral_gauss_kronrod_sharp_peak() {
+    let equation = |x: f64| 1. / (1e-4 + x.powf(2.));
+
+    let result = calculate_single_integral_gauss_kronrod(
+        equation,
+        -1.,
+        1.,
+        0.,
+        1e-10,
+        GaussKronrodRule::G10K21,
+        100,
+    )
+    .unwrap();
+    let expected = 200. * 100f64.atan();
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        expected,
+        result - expected
+    );
+
+    assert_approx_eq!(result, expected, 1e-7);
+}
+
+#[test]
+fn calculate_single_integral_gauss_kronrod_reversed_bounds() {
+    let equation = |x: f64| x.cos();
+
+    let result = calculate_single_integral_gauss_kronrod(
+        equation,
+        std::f64::consts::FRAC_PI_2,
+        0.,
+        1e-12,
+        0.,
+        GaussKronrodRule::G7K15,
+        50,
+    )
+    .unwrap();
+
+    assert_approx_eq!(result, -1., 1e-12);
+}
+
+#[test]
+fn calculate_single_integral_gauss_kronrod_limit_reached() {
+    let equation = |x: f64| 1. / (1e-4 + x.powf(2.));
+
+    let result = calculate_single_integral_gauss_kronrod(
+        equation,
+        -1.,
+        1.,
+        0.,
+        1e-10,
+        GaussKronrodRule::G7K15,
+        3,
+    );
+
+    match result {
+        Err(Error::SubdivisionLimitReached { limit, .. }) => assert_eq!(limit, 3),
+        _ => panic!("expected SubdivisionLimitReached, got {:?}", result),
+    }
+}
+
+#[test]
+fn calculate_single_integral_gauss_kronrod_invalid_limit() {
+    let equation = |x: f64| x.sin();
+
+    let result = calculate_single_integral_gauss_kronrod(
+        equation,
+        0.,
+        1.,
+        1e-10,
+        0.,
+        GaussKronrodRule::G7K15,
+        0,
+    );
+
+    match result {
+        Err(Error::InvalidSubdivisionLimit { limit: 0, .. }) => {}
+        _ => panic!("expected InvalidSubdivisionLimit, got {:?}", result),
+    }
+
+    let result = calculate_single_integral_gauss_kronrod(
+        equation,
+        0.,
+        1.,
+        1e-10,
+        0.,
+        GaussKronrodRule::G7K15,
+        usize::MAX,
+    )
+    .unwrap();
+    assert_approx_eq!(result, 1. - 1f64.cos(), 1e-12);
+}
+
+#[test]
+fn calculate_double_integral_gauss_kronrod_circle() {
+    let equation = |_x, _y| 1.;
+    let second_integral_begin = |_x| -0.;
+    let second_integral_end = |x: f64| (1. - x.powf(2.)).sqrt();
+
+    let result = calculate_double_integral_gauss_kronrod(
+        equation,
+        -1.,
+        1.,
+        second_integral_begin,
+        second_integral_end,
+        1e-10,
+        0.,
+        GaussKronrodRule::G7K15,
+        200,
+    )
+    .unwrap();
+
+    assert_approx_eq!(result, std::f64::consts::FRAC_PI_2, 1e-9);
+}
+
+#[test]
+fn calculate_triple_integral_gauss_kronrod_peak_per_slice() {
+    let equation = |x: f64, y: f64, z: f64| 1. / (1e-3 + (z - x * y).powf(2.));
+
+    let result = calculate_triple_integral_gauss_kronrod(
+        equation,
+        0.,
+        1.,
+        |_x| 0.,
+        |_x| 1.,
+        |_x, _y| 0.,
+        |_x, _y| 1.,
+        1e-9,
+        1e-9,
+        GaussKronrodRule::G10K21,
+        100,
+    )
+    .unwrap();
+
+    let scale = 1e-3f64.sqrt();
+    let expected = calculate_double_integral_gauss_kronrod(
+        |x: f64, y: f64| (((1. - x * y) / scale).atan() + (x * y / scale).atan()) / scale,
+        0.,
+        1.,
+        |_x| 0.,
+        |_x| 1.,
+        1e-11,
+        1e-11,
+        GaussKronrodRule::G10K21,
+        100,
+    )
+    .unwrap();
+
+    assert_approx_eq!(result, expected, 1e-6);
+}
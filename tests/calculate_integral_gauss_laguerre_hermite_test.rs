@@ -0,0 +1,120 @@
+use assert_approx_eq::assert_approx_eq;
+use sequential_integration::{
+    calculate_double_integral_gauss_hermite, calculate_double_integral_gauss_laguerre,
+    calculate_single_integral_gauss_hermite, calculate_single_integral_gauss_laguerre,
+    calculate_triple_integral_gauss_hermite, calculate_triple_integral_gauss_laguerre,
+    engine::quadrature::{
+        gauss_hermite::GaussHermiteQuadrature, gauss_laguerre::GaussLaguerreQuadrature,
+    },
+    errors::Error,
+};
+
+#[test]
+fn calculate_single_integral_gauss_laguerre_polynomial() {
+    let equation = |x: f64| x.powi(3);
+
+    let result = calculate_single_integral_gauss_laguerre(equation, 10, 0.).unwrap();
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        6.,
+        result - 6.
+    );
+    assert_approx_eq!(result, 6., 1e-11);
+}
+
+#[test]
+fn calculate_single_integral_gauss_laguerre_generalized() {
+    let sqrt_pi = std::f64::consts::PI.sqrt();
+
+    let result = calculate_single_integral_gauss_laguerre(|_x| 1., 8, 0.5).unwrap();
+    assert_approx_eq!(result, sqrt_pi / 2., 1e-12);
+
+    let result = calculate_single_integral_gauss_laguerre(|x: f64| x, 8, 0.5).unwrap();
+    assert_approx_eq!(result, 3. * sqrt_pi / 4., 1e-12);
+
+    // Γ(0.2)
+    let result = calculate_single_integral_gauss_laguerre(|_x| 1., 1, -0.8).unwrap();
+    assert_approx_eq!(result, 4.590843711998803, 1e-12);
+}
+
+#[test]
+fn calculate_single_integral_gauss_laguerre_smooth() {
+    let equation = |x: f64| 1. / (1. + x).powi(2);
+
+    let result = calculate_single_integral_gauss_laguerre(equation, 40, 0.).unwrap();
+    // 1 - e E1(1)
+    let expected = 0.40365263767680114;
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        expected,
+        result - expected
+    );
+    assert_approx_eq!(result, expected, 1e-6);
+}
+
+#[test]
+fn calculate_single_integral_gauss_hermite_oscillator() {
+    let sqrt_pi = std::f64::consts::PI.sqrt();
+
+    // <x²> of the ground state of the quantum harmonic oscillator
+    let result = calculate_single_integral_gauss_hermite(|x: f64| x.powi(2) / sqrt_pi, 10).unwrap();
+    assert_approx_eq!(result, 0.5, 1e-13);
+
+    let result = calculate_single_integral_gauss_hermite(|x: f64| x.cos(), 20).unwrap();
+    assert_approx_eq!(result, sqrt_pi * (-0.25f64).exp(), 1e-13);
+}
+
+#[test]
+fn gauss_hermite_quadrature_many_points() {
+    let quadrature = GaussHermiteQuadrature::new(100).unwrap();
+
+    let sum: f64 = quadrature.weights().iter().sum();
+    assert_approx_eq!(sum, std::f64::consts::PI.sqrt(), 1e-12);
+    assert!(quadrature.nodes().windows(2).all(|pair| pair[0] > pair[1]));
+
+    let quadrature = GaussLaguerreQuadrature::new(100, 0.).unwrap();
+
+    let sum: f64 = quadrature.weights().iter().sum();
+    assert_approx_eq!(sum, 1., 1e-10);
+}
+
+#[test]
+fn calculate_double_and_triple_integral_gauss_hermite() {
+    let pi = std::f64::consts::PI;
+
+    let result =
+        calculate_double_integral_gauss_hermite(|x: f64, y: f64| x.powi(2) + y.powi(2), 6).unwrap();
+    assert_approx_eq!(result, pi, 1e-12);
+
+    let result = calculate_triple_integral_gauss_hermite(|_x, _y, _z| 1., 4).unwrap();
+    assert_approx_eq!(result, pi.powf(1.5), 1e-12);
+}
+
+#[test]
+fn calculate_double_and_triple_integral_gauss_laguerre() {
+    let result =
+        calculate_double_integral_gauss_laguerre(|x: f64, y: f64| x * y.powi(2), 5, 0.).unwrap();
+    assert_approx_eq!(result, 2., 1e-12);
+
+    let result =
+        calculate_triple_integral_gauss_laguerre(|x: f64, y: f64, z: f64| x * y * z, 4, 1.)
+            .unwrap();
+    assert_approx_eq!(result, 8., 1e-11);
+}
+
+#[test]
+fn calculate_single_integral_gauss_laguerre_invalid_parameters() {
+    match calculate_single_integral_gauss_laguerre(|x| x, 5, -1.) {
+        Err(Error::InvalidWeightExponent { .. }) => {}
+        result => panic!("expected InvalidWeightExponent, got {:?}", result),
+    }
+
+    match calculate_single_integral_gauss_hermite(|x| x, 0) {
+        Err(Error::InvalidPointCount { .. }) => {}
+        result => panic!("expected InvalidPointCount, got {:?}", result),
+    }
+}
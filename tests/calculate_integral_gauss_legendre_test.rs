@@ -0,0 +1,157 @@
+use assert_approx_eq::assert_approx_eq;
+use sequential_integration::{
+    calculate_double_integral_gauss_legendre, calculate_single_integral_gauss_legendre,
+    calculate_triple_integral_gauss_legendre,
+};
+
+#[test]
+fn calculate_single_integral_gauss_legendre_polynomial_is_exact() {
+    let equation = |x: f64| 7. * x.powi(13) - 3. * x.powi(6) + 1.;
+
+    let result = calculate_single_integral_gauss_legendre(equation, -1., 2., 3., 7).unwrap();
+    let expected = (2f64.powi(14) - 1.) / 2. - 3. * (2f64.powi(7) + 1.) / 7. + 3.;
+
+    assert_approx_eq!(result, expected, 1e-9);
+}
+
+#[test]
+fn calculate_single_integral_gauss_legendre_not_const_equation() {
+    let equation = |x: f64| x.exp() * x.cos();
+
+    let result = calculate_single_integral_gauss_legendre(equation, 0., 3., 0.7, 10).unwrap();
+    let expected = (3f64.exp() * (3f64.cos() + 3f64.sin()) - 1.) / 2.;
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        expected,
+        result - expected
+    );
+
+    assert_approx_eq!(result, expected, 1e-12);
+}
+
+#[test]
+fn calculate_single_integral_gauss_legendre_invalid_points() {
+    let equation = |x: f64| x;
+
+    assert!(calculate_single_integral_gauss_legendre(equation, 0., 1., 0.1, 0).is_err());
+    assert!(calculate_single_integral_gauss_legendre(equation, 0., 1., 0.1, 1).is_err());
+    assert!(calculate_single_integral_gauss_legendre(equation, 0., 1., 0.1, 65).is_err());
+    assert!(calculate_single_integral_gauss_legendre(equation, 0., 1., 0.1, 64).is_ok());
+    assert!(calculate_single_integral_gauss_legendre(equation, 0., 1., 0.1, 2).is_ok());
+}
+
+#[test]
+fn calculate_double_integral_gauss_legendre_not_const_equation() {
+    let equation = |x: f64, y: f64| (x * y).exp();
+
+    let result =
+        calculate_double_integral_gauss_legendre(equation, 0., 1., 0.5, |_x| 0., |_x| 2., 0.5, 8)
+            .unwrap();
+
+    assert_approx_eq!(result, 3.68387151054041, 1e-12);
+}
+
+#[test]
+fn calculate_triple_integral_gauss_legendre_cube() {
+    let equation = |x: f64, y: f64, z: f64| x.powf(2.) + y.powf(2.) + z.powf(2.);
+
+    let result = calculate_triple_integral_gauss_legendre(
+        equation,
+        -1.,
+        1.,
+        0.5,
+        |_x| 0.,
+        |_x| 2.,
+        0.5,
+        |_x, _y| 0.,
+        |_x, _y| 2.,
+        0.5,
+        2,
+    )
+    .unwrap();
+
+    assert_approx_eq!(result, 8. * (1. / 3. + 4. / 3. + 4. / 3.), 1e-10);
+}
+
+#[test]
+fn calculate_single_integral_gauss_legendre_many_points() {
+    let equation = |x: f64| x.exp();
+
+    for points in [16, 33, 64].iter() {
+        let result =
+            calculate_single_integral_gauss_legendre(equation, 0., 1., 1., *points).unwrap();
+
+        assert_approx_eq!(result, std::f64::consts::E - 1., 1e-13);
+    }
+}
+
+#[test]
+fn calculate_double_integral_gauss_legendre_not_const_bounds() {
+    let equation = |x: f64, y: f64| x + y;
+
+    let result =
+        calculate_double_integral_gauss_legendre(equation, 0., 1., 0.5, |_x| 0., |x| x * x, 0.5, 4)
+            .unwrap();
+    let expected = 1. / 4. + 1. / 10.;
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        expected,
+        result - expected
+    );
+
+    assert_approx_eq!(result, expected, 1e-14);
+}
+
+#[test]
+fn calculate_double_integral_gauss_legendre_half_disc() {
+    let equation = |_x, _y| 1.;
+
+    for (step, tolerance) in [(0.1, 2e-5), (0.01, 1e-6)].iter() {
+        let result = calculate_double_integral_gauss_legendre(
+            equation,
+            -1.,
+            1.,
+            *step,
+            |_x| 0.,
+            |x: f64| (1. - x.powf(2.)).sqrt(),
+            *step,
+            8,
+        )
+        .unwrap();
+
+        println!(
+            "result: {}, expected: {}, diff: {}",
+            result,
+            std::f64::consts::FRAC_PI_2,
+            result - std::f64::consts::FRAC_PI_2
+        );
+
+        assert_approx_eq!(result, std::f64::consts::FRAC_PI_2, *tolerance);
+    }
+}
+
+#[test]
+fn calculate_triple_integral_gauss_legendre_not_const_bounds() {
+    let equation = |x: f64, y: f64, z: f64| x * y * z;
+
+    let result = calculate_triple_integral_gauss_legendre(
+        equation,
+        0.,
+        1.,
+        0.5,
+        |_x| 0.,
+        |x| x,
+        0.5,
+        |_x, _y| 0.,
+        |x, y| x + y,
+        0.5,
+        3,
+    )
+    .unwrap();
+
+    assert_approx_eq!(result, 17. / 144., 1e-14);
+}
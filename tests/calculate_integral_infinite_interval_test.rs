@@ -0,0 +1,223 @@
+use assert_approx_eq::assert_approx_eq;
+use sequential_integration::{
+    calculate_double_integral_adaptive_simpson, calculate_double_integral_gauss_kronrod,
+    calculate_multiple_integral_gauss_kronrod, calculate_single_integral_adaptive_simpson,
+    calculate_single_integral_gauss_kronrod, calculate_single_integral_simpson,
+    calculate_triple_integral_gauss_kronrod, errors::Error, DimensionBounds, GaussKronrodRule,
+};
+
+#[test]
+fn calculate_single_integral_gauss_kronrod_upper_infinite() {
+    let equation = |x: f64| (-x).exp();
+
+    let result = calculate_single_integral_gauss_kronrod(
+        equation,
+        0.,
+        f64::INFINITY,
+        1e-12,
+        0.,
+        GaussKronrodRule::G7K15,
+        100,
+    )
+    .unwrap();
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        1.,
+        result - 1.
+    );
+    assert_approx_eq!(result, 1., 1e-11);
+}
+
+#[test]
+fn calculate_single_integral_gauss_kronrod_lower_infinite() {
+    let equation = |x: f64| 1. / (1. + x.powi(2));
+
+    let result = calculate_single_integral_gauss_kronrod(
+        equation,
+        f64::NEG_INFINITY,
+        1.,
+        1e-12,
+        0.,
+        GaussKronrodRule::G10K21,
+        100,
+    )
+    .unwrap();
+    let expected = 3. * std::f64::consts::FRAC_PI_4;
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        expected,
+        result - expected
+    );
+    assert_approx_eq!(result, expected, 1e-10);
+}
+
+#[test]
+fn calculate_single_integral_adaptive_simpson_whole_line() {
+    let equation = |x: f64| (-x.powi(2)).exp();
+
+    let result = calculate_single_integral_adaptive_simpson(
+        equation,
+        f64::NEG_INFINITY,
+        f64::INFINITY,
+        1e-10,
+        0.,
+    )
+    .unwrap();
+    let expected = std::f64::consts::PI.sqrt();
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        expected,
+        result - expected
+    );
+    assert_approx_eq!(result, expected, 1e-8);
+}
+
+#[test]
+fn calculate_single_integral_gauss_kronrod_reversed_infinite() {
+    let equation = |x: f64| (-x).exp();
+
+    let result = calculate_single_integral_gauss_kronrod(
+        equation,
+        f64::INFINITY,
+        0.,
+        1e-12,
+        0.,
+        GaussKronrodRule::G7K15,
+        100,
+    )
+    .unwrap();
+
+    assert_approx_eq!(result, -1., 1e-11);
+}
+
+#[test]
+fn calculate_double_integral_gauss_kronrod_outer_infinite() {
+    let equation = |x: f64, y: f64| (-x).exp() * y;
+
+    let result = calculate_double_integral_gauss_kronrod(
+        equation,
+        0.,
+        f64::INFINITY,
+        |_x| 0.,
+        |x| x,
+        1e-10,
+        0.,
+        GaussKronrodRule::G7K15,
+        100,
+    )
+    .unwrap();
+
+    // ∫ e^-x x²/2 dx over [0, ∞) = 1
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        1.,
+        result - 1.
+    );
+    assert_approx_eq!(result, 1., 1e-9);
+}
+
+#[test]
+fn calculate_triple_integral_gauss_kronrod_outer_infinite() {
+    let equation = |x: f64, _y: f64, _z: f64| (-x.powi(2)).exp();
+
+    let result = calculate_triple_integral_gauss_kronrod(
+        equation,
+        f64::NEG_INFINITY,
+        f64::INFINITY,
+        |_x| 0.,
+        |_x| 2.,
+        |_x, _y| 0.,
+        |_x, y| y,
+        1e-10,
+        0.,
+        GaussKronrodRule::G7K15,
+        100,
+    )
+    .unwrap();
+    let expected = 2. * std::f64::consts::PI.sqrt();
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        expected,
+        result - expected
+    );
+    assert_approx_eq!(result, expected, 1e-8);
+}
+
+#[test]
+fn calculate_multiple_integral_gauss_kronrod_outer_infinite() {
+    let equation = |x: &[f64; 2]| (-x[0]).exp();
+    let dimensions = [
+        DimensionBounds::constant(0., f64::INFINITY),
+        DimensionBounds::new(|_x| 0., |_x| 3.),
+    ];
+
+    let result = calculate_multiple_integral_gauss_kronrod(
+        equation,
+        &dimensions,
+        1e-10,
+        0.,
+        GaussKronrodRule::G7K15,
+        100,
+    )
+    .unwrap();
+
+    assert_approx_eq!(result, 3., 1e-9);
+}
+
+#[test]
+fn calculate_double_integral_adaptive_simpson_infinite_inner_bound() {
+    let equation = |x: f64, y: f64| (-x - y).exp();
+
+    match calculate_double_integral_adaptive_simpson(
+        equation,
+        0.,
+        1.,
+        |_x| 0.,
+        |_x| f64::INFINITY,
+        1e-8,
+        0.,
+    ) {
+        Err(Error::InfiniteInnerBound { .. }) => {}
+        result => panic!("expected InfiniteInnerBound, got {:?}", result),
+    }
+}
+
+#[test]
+fn calculate_single_integral_simpson_infinite_bound() {
+    let equation = |x: f64| (-x).exp();
+
+    let result = calculate_single_integral_simpson(equation, 0., f64::INFINITY, 0.01).unwrap();
+    assert_approx_eq!(result, 1., 1e-8);
+
+    let equation = |x: f64| 1. / (1. + x * x);
+
+    let result =
+        calculate_single_integral_simpson(equation, f64::NEG_INFINITY, f64::INFINITY, 0.01)
+            .unwrap();
+    assert_approx_eq!(result, std::f64::consts::PI, 1e-8);
+}
+
+#[test]
+fn calculate_single_integral_adaptive_simpson_slow_decay() {
+    let equation = |x: f64| 1. / (1. + x * x);
+
+    let result =
+        calculate_single_integral_adaptive_simpson(equation, 0., f64::INFINITY, 1e-10, 0.).unwrap();
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        std::f64::consts::FRAC_PI_2,
+        result - std::f64::consts::FRAC_PI_2
+    );
+    assert_approx_eq!(result, std::f64::consts::FRAC_PI_2, 1e-8);
+}
@@ -0,0 +1,221 @@
+use std::f64::consts::PI;
+
+use assert_approx_eq::assert_approx_eq;
+use sequential_integration::{
+    calculate_double_integral_monte_carlo, calculate_multiple_integral_monte_carlo,
+    calculate_single_integral_monte_carlo, calculate_triple_integral_monte_carlo,
+    engine::quadrature::monte_carlo::Xoshiro256StarStar, errors::Error, DimensionBounds,
+    MonteCarloSampling,
+};
+
+#[test]
+fn calculate_single_integral_monte_carlo_plain() {
+    let equation = |x: f64| x.powi(2);
+
+    let outcome = calculate_single_integral_monte_carlo(
+        equation,
+        0.,
+        3.,
+        100_000,
+        7,
+        MonteCarloSampling::Plain,
+    )
+    .unwrap();
+
+    println!(
+        "result: {}, expected: {}, diff: {}, error: {}",
+        outcome.value,
+        9.,
+        outcome.value - 9.,
+        outcome.error
+    );
+    assert!((outcome.value - 9.).abs() < 4. * outcome.error);
+    assert!(outcome.error < 0.05);
+    assert_eq!(outcome.evaluations, 100_000);
+}
+
+#[test]
+fn calculate_single_integral_monte_carlo_stratified_reduces_error() {
+    let equation = |x: f64| x.sin();
+
+    let plain = calculate_single_integral_monte_carlo(
+        equation,
+        0.,
+        PI,
+        10_000,
+        1,
+        MonteCarloSampling::Plain,
+    )
+    .unwrap();
+    let stratified = calculate_single_integral_monte_carlo(
+        equation,
+        0.,
+        PI,
+        10_000,
+        1,
+        MonteCarloSampling::Stratified { strata: 1000 },
+    )
+    .unwrap();
+
+    println!(
+        "plain: {} ± {}, stratified: {} ± {}",
+        plain.value, plain.error, stratified.value, stratified.error
+    );
+    assert!(stratified.error * 100. < plain.error);
+    assert_approx_eq!(stratified.value, 2., 1e-4);
+    assert_eq!(stratified.panels, 1000);
+}
+
+#[test]
+fn calculate_double_integral_monte_carlo_triangle() {
+    let equation = |x: f64, y: f64| x * y;
+
+    let outcome = calculate_double_integral_monte_carlo(
+        equation,
+        0.,
+        1.,
+        |_x| 0.,
+        |x| x,
+        40_000,
+        3,
+        MonteCarloSampling::Stratified { strata: 100 },
+    )
+    .unwrap();
+
+    println!(
+        "result: {}, expected: {}, diff: {}, error: {}",
+        outcome.value,
+        0.125,
+        outcome.value - 0.125,
+        outcome.error
+    );
+    assert!((outcome.value - 0.125).abs() < 4. * outcome.error);
+    assert!(outcome.error < 1e-3);
+}
+
+#[test]
+fn calculate_triple_integral_monte_carlo_ball() {
+    let outcome = calculate_triple_integral_monte_carlo(
+        |_x, _y, _z| 1.,
+        -1.,
+        1.,
+        |x| -(1. - x.powi(2)).sqrt(),
+        |x| (1. - x.powi(2)).sqrt(),
+        |x, y| -(1. - x.powi(2) - y.powi(2)).max(0.).sqrt(),
+        |x, y| (1. - x.powi(2) - y.powi(2)).max(0.).sqrt(),
+        64_000,
+        11,
+        MonteCarloSampling::Stratified { strata: 20 },
+    )
+    .unwrap();
+    let expected = 4. * PI / 3.;
+
+    println!(
+        "result: {}, expected: {}, diff: {}, error: {}",
+        outcome.value,
+        expected,
+        outcome.value - expected,
+        outcome.error
+    );
+    assert!((outcome.value - expected).abs() < 4. * outcome.error);
+    assert!(outcome.error < 1e-2);
+}
+
+#[test]
+fn calculate_multiple_integral_monte_carlo_simplex() {
+    // Volume of the 5-simplex x₁ + … + x₅ ≤ 1
+    let dimensions = [
+        DimensionBounds::constant(0., 1.),
+        DimensionBounds::new(|_| 0., |outer| 1. - outer.iter().sum::<f64>()),
+        DimensionBounds::new(|_| 0., |outer| 1. - outer.iter().sum::<f64>()),
+        DimensionBounds::new(|_| 0., |outer| 1. - outer.iter().sum::<f64>()),
+        DimensionBounds::new(|_| 0., |outer| 1. - outer.iter().sum::<f64>()),
+    ];
+
+    let outcome = calculate_multiple_integral_monte_carlo(
+        |_point: &[f64; 5]| 1.,
+        &dimensions,
+        200_000,
+        5,
+        MonteCarloSampling::Plain,
+    )
+    .unwrap();
+
+    println!(
+        "result: {}, expected: {}, diff: {}, error: {}",
+        outcome.value,
+        1. / 120.,
+        outcome.value - 1. / 120.,
+        outcome.error
+    );
+    assert!((outcome.value - 1. / 120.).abs() < 4. * outcome.error);
+}
+
+#[test]
+fn calculate_single_integral_monte_carlo_reproducible() {
+    let equation = |x: f64| x.exp();
+    let sampling = MonteCarloSampling::Stratified { strata: 10 };
+
+    let first =
+        calculate_single_integral_monte_carlo(equation, 0., 1., 1000, 42, sampling).unwrap();
+    let second =
+        calculate_single_integral_monte_carlo(equation, 0., 1., 1000, 42, sampling).unwrap();
+    let other_seed =
+        calculate_single_integral_monte_carlo(equation, 0., 1., 1000, 43, sampling).unwrap();
+
+    assert_eq!(first.value.to_bits(), second.value.to_bits());
+    assert_eq!(first.error.to_bits(), second.error.to_bits());
+    assert_ne!(first.value.to_bits(), other_seed.value.to_bits());
+
+    let mut rng = Xoshiro256StarStar::new(42);
+    let mean = (0..100_000).map(|_| rng.next_f64()).sum::<f64>() / 100_000.;
+    assert_approx_eq!(mean, 0.5, 1e-2);
+}
+
+#[test]
+fn calculate_single_integral_monte_carlo_invalid_parameters() {
+    let equation = |x: f64| x;
+
+    match calculate_single_integral_monte_carlo(equation, 0., 1., 1, 0, MonteCarloSampling::Plain) {
+        Err(Error::InvalidSampleCount { min: 2, .. }) => {}
+        result => panic!("expected InvalidSampleCount, got {:?}", result),
+    }
+
+    match calculate_double_integral_monte_carlo(
+        |x, y| x * y,
+        0.,
+        1.,
+        |_x| 0.,
+        |_x| 1.,
+        100,
+        0,
+        MonteCarloSampling::Stratified { strata: 10 },
+    ) {
+        Err(Error::InvalidSampleCount { min: 200, .. }) => {}
+        result => panic!("expected InvalidSampleCount, got {:?}", result),
+    }
+
+    match calculate_single_integral_monte_carlo(
+        equation,
+        0.,
+        1.,
+        100,
+        0,
+        MonteCarloSampling::Stratified { strata: 0 },
+    ) {
+        Err(Error::InvalidStrataCount { strata: 0, .. }) => {}
+        result => panic!("expected InvalidStrataCount, got {:?}", result),
+    }
+
+    match calculate_single_integral_monte_carlo(
+        equation,
+        0.,
+        f64::INFINITY,
+        100,
+        0,
+        MonteCarloSampling::Plain,
+    ) {
+        Err(Error::NonFiniteBound { .. }) => {}
+        result => panic!("expected NonFiniteBound, got {:?}", result),
+    }
+}
@@ -0,0 +1,123 @@
+use assert_approx_eq::assert_approx_eq;
+use sequential_integration::{
+    calculate_multiple_integral_adaptive_simpson, calculate_multiple_integral_gauss_kronrod,
+    calculate_triple_integral_gauss_kronrod, errors::Error, DimensionBounds, GaussKronrodRule,
+};
+
+#[test]
+fn calculate_multiple_integral_gauss_kronrod_simplex() {
+    let equation = |_x: &[f64; 4]| 1.;
+    let dimensions = [
+        DimensionBounds::constant(0., 1.),
+        DimensionBounds::new(|_x| 0., |x| 1. - x[0]),
+        DimensionBounds::new(|_x| 0., |x| 1. - x[0] - x[1]),
+        DimensionBounds::new(|_x| 0., |x| 1. - x[0] - x[1] - x[2]),
+    ];
+
+    let result = calculate_multiple_integral_gauss_kronrod(
+        equation,
+        &dimensions,
+        1e-10,
+        0.,
+        GaussKronrodRule::G7K15,
+        50,
+    )
+    .unwrap();
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        1. / 24.,
+        result - 1. / 24.
+    );
+
+    assert_approx_eq!(result, 1. / 24., 1e-12);
+}
+
+#[test]
+fn calculate_multiple_integral_gauss_kronrod_six_dimensions() {
+    let equation = |x: &[f64; 6]| x.iter().product::<f64>();
+    let dimensions = [
+        DimensionBounds::constant(0., 1.),
+        DimensionBounds::constant(0., 1.),
+        DimensionBounds::constant(0., 1.),
+        DimensionBounds::constant(0., 1.),
+        DimensionBounds::constant(0., 1.),
+        DimensionBounds::constant(0., 1.),
+    ];
+
+    let result = calculate_multiple_integral_gauss_kronrod(
+        equation,
+        &dimensions,
+        1e-10,
+        0.,
+        GaussKronrodRule::G7K15,
+        50,
+    )
+    .unwrap();
+
+    assert_approx_eq!(result, 1. / 64., 1e-12);
+}
+
+#[test]
+fn calculate_multiple_integral_gauss_kronrod_same_as_triple() {
+    let equation = |x: f64, y: f64, z: f64| (x * y + z).cos();
+
+    let result = calculate_multiple_integral_gauss_kronrod(
+        |x: &[f64; 3]| equation(x[0], x[1], x[2]),
+        &[
+            DimensionBounds::constant(0., 1.),
+            DimensionBounds::new(|x| x[0].powf(2.), |x| 1. + x[0]),
+            DimensionBounds::new(|_x| 0., |x| x[0] * x[1]),
+        ],
+        1e-10,
+        0.,
+        GaussKronrodRule::G10K21,
+        50,
+    )
+    .unwrap();
+    let expected = calculate_triple_integral_gauss_kronrod(
+        equation,
+        0.,
+        1.,
+        |x: f64| x.powf(2.),
+        |x| 1. + x,
+        |_x, _y| 0.,
+        |x, y| x * y,
+        1e-10,
+        0.,
+        GaussKronrodRule::G10K21,
+        50,
+    )
+    .unwrap();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn calculate_multiple_integral_adaptive_simpson_reversed_bounds() {
+    let equation = |x: &[f64; 2]| x[0] * x[1];
+    let dimensions = [
+        DimensionBounds::constant(1., 0.),
+        DimensionBounds::new(|x| x[0], |_x| 0.),
+    ];
+
+    let result =
+        calculate_multiple_integral_adaptive_simpson(equation, &dimensions, 1e-10, 0.).unwrap();
+
+    assert_approx_eq!(result, 1. / 8., 1e-10);
+}
+
+#[test]
+fn calculate_multiple_integral_adaptive_simpson_infinite_inner_bound() {
+    let equation = |_x: &[f64; 2]| 1.;
+    let dimensions = [
+        DimensionBounds::constant(0., 1.),
+        DimensionBounds::new(|_x| 0., |x| 1. / x[0]),
+    ];
+
+    match calculate_multiple_integral_adaptive_simpson(equation, &dimensions, 1e-8, 0.) {
+        Err(Error::InfiniteInnerBound { .. }) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+}
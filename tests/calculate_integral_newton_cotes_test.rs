@@ -0,0 +1,225 @@
+use assert_approx_eq::assert_approx_eq;
+use sequential_integration::{
+    calculate_double_integral_newton_cotes, calculate_single_integral_newton_cotes,
+    calculate_single_integral_simpson, calculate_triple_integral_newton_cotes, errors::Error,
+    NewtonCotesRule, Resolution,
+};
+use std::cell::Cell;
+
+#[test]
+fn calculate_single_integral_newton_cotes_simpson_same_as_simpson() {
+    let equation = |x: f64| x.sin() * x.exp();
+
+    let result =
+        calculate_single_integral_newton_cotes(equation, 0., 2., 0.01, NewtonCotesRule::Simpson)
+            .unwrap();
+    let expected = calculate_single_integral_simpson(equation, 0., 2., 0.01).unwrap();
+
+    assert_approx_eq!(result, expected, 1e-13);
+}
+
+#[test]
+fn calculate_single_integral_newton_cotes_polynomial_exact() {
+    let equation = |x: f64| x.powi(5) - 2. * x.powi(3) + x;
+    let expected = 64. / 6. - 8. + 2.;
+
+    for rule in [NewtonCotesRule::Boole, NewtonCotesRule::SixPoint].iter() {
+        let result = calculate_single_integral_newton_cotes(equation, 0., 2., 0.25, *rule).unwrap();
+
+        assert_approx_eq!(result, expected, 1e-12);
+    }
+
+    let equation = |x: f64| x.powi(3) - x;
+    let result = calculate_single_integral_newton_cotes(
+        equation,
+        0.,
+        2.,
+        0.25,
+        NewtonCotesRule::SimpsonThreeEighths,
+    )
+    .unwrap();
+
+    assert_approx_eq!(result, 2., 1e-12);
+}
+
+#[test]
+fn calculate_single_integral_newton_cotes_boole_sixth_order() {
+    let equation = |x: f64| x.exp();
+    let expected = 1f64.exp() - 1.;
+
+    let fine = calculate_single_integral_newton_cotes(
+        equation,
+        0.,
+        1.,
+        Resolution::Panels(8),
+        NewtonCotesRule::Boole,
+    )
+    .unwrap();
+    let coarse = calculate_single_integral_newton_cotes(
+        equation,
+        0.,
+        1.,
+        Resolution::Panels(4),
+        NewtonCotesRule::Boole,
+    )
+    .unwrap();
+
+    let ratio = (coarse - expected) / (fine - expected);
+    println!("fine: {}, coarse: {}, ratio: {}", fine, coarse, ratio);
+    assert!(ratio > 60. && ratio < 68.);
+}
+
+#[test]
+fn calculate_single_integral_newton_cotes_remainder_equal_panels() {
+    let evaluations = Cell::new(0);
+    let equation = |x: f64| {
+        evaluations.set(evaluations.get() + 1);
+        x.powi(5)
+    };
+
+    // nine steps do not fill two six-point panels, so both are shrunk to [0, 0.45], [0.45, 0.9]
+    let result =
+        calculate_single_integral_newton_cotes(equation, 0., 0.9, 0.1, NewtonCotesRule::SixPoint)
+            .unwrap();
+
+    // the end of the first panel is the beginning of the second one
+    assert_eq!(evaluations.get(), 2 * 5 + 1);
+    assert_approx_eq!(result, 0.9f64.powi(6) / 6., 1e-13);
+
+    evaluations.set(0);
+    // two and a half Boole panels become three equal ones
+    let result =
+        calculate_single_integral_newton_cotes(equation, 0., 1., 0.1, NewtonCotesRule::Boole)
+            .unwrap();
+
+    assert_eq!(evaluations.get(), 3 * 4 + 1);
+    assert_approx_eq!(result, 1. / 6., 1e-13);
+}
+
+#[test]
+fn calculate_single_integral_newton_cotes_fractional_remainder() {
+    let equation = |x: f64| x.cos();
+
+    let result = calculate_single_integral_newton_cotes(
+        equation,
+        0.,
+        1.05,
+        0.1,
+        NewtonCotesRule::SimpsonThreeEighths,
+    )
+    .unwrap();
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        1.05f64.sin(),
+        result - 1.05f64.sin()
+    );
+    assert_approx_eq!(result, 1.05f64.sin(), 1e-6);
+}
+
+#[test]
+fn calculate_double_integral_newton_cotes_order_per_dimension() {
+    let equation = |x: f64, y: f64| x.powi(5) * y.powi(3);
+
+    let result = calculate_double_integral_newton_cotes(
+        equation,
+        0.,
+        1.,
+        0.25,
+        |_x| 0.,
+        |_x| 2.,
+        0.5,
+        NewtonCotesRule::Boole,
+        NewtonCotesRule::SimpsonThreeEighths,
+    )
+    .unwrap();
+
+    assert_approx_eq!(result, 4. / 6., 1e-12);
+}
+
+#[test]
+fn calculate_triple_integral_newton_cotes_cube() {
+    let equation = |x: f64, y: f64, z: f64| (x + y + z).exp();
+
+    let result = calculate_triple_integral_newton_cotes(
+        equation,
+        0.,
+        1.,
+        Resolution::Panels(2),
+        |_x| 0.,
+        |_x| 1.,
+        Resolution::Panels(2),
+        |_x, _y| 0.,
+        |_x, _y| 1.,
+        Resolution::Panels(2),
+        NewtonCotesRule::SixPoint,
+        NewtonCotesRule::Boole,
+        NewtonCotesRule::Simpson,
+    )
+    .unwrap();
+    let expected = (1f64.exp() - 1.).powi(3);
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        expected,
+        result - expected
+    );
+    assert_approx_eq!(result, expected, 1e-3);
+}
+
+#[test]
+fn newton_cotes_rule_from_points() {
+    assert_eq!(
+        NewtonCotesRule::from_points(5).unwrap(),
+        NewtonCotesRule::Boole
+    );
+
+    for points in [0, 2, 7].iter() {
+        match NewtonCotesRule::from_points(*points) {
+            Err(Error::InvalidPointCount { .. }) => {}
+            result => panic!("expected InvalidPointCount, got {:?}", result),
+        }
+    }
+}
+
+#[test]
+fn calculate_double_integral_newton_cotes_triangle() {
+    let result = calculate_double_integral_newton_cotes(
+        |_x, _y| 1.,
+        0.,
+        1.,
+        0.1,
+        |_x| 0.,
+        |x| x,
+        0.1,
+        NewtonCotesRule::Simpson,
+        NewtonCotesRule::Simpson,
+    )
+    .unwrap();
+
+    assert_approx_eq!(result, 0.5, 1e-14);
+
+    let result = calculate_double_integral_newton_cotes(
+        |x: f64, y: f64| x * y.powi(3),
+        0.,
+        1.,
+        0.25,
+        |_x| 0.,
+        |x| x,
+        0.5,
+        NewtonCotesRule::Boole,
+        NewtonCotesRule::Simpson,
+    )
+    .unwrap();
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        1. / 24.,
+        result - 1. / 24.
+    );
+
+    assert_approx_eq!(result, 1. / 24., 1e-14);
+}
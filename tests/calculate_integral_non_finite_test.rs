@@ -0,0 +1,163 @@
+use assert_approx_eq::assert_approx_eq;
+use sequential_integration::{
+    calculate_double_integral_simpson, calculate_double_integral_simpson_with_policy,
+    calculate_single_integral_simpson, calculate_single_integral_simpson_with_policy,
+    calculate_triple_integral_simpson_with_policy, errors::Error, NonFinitePolicy,
+};
+
+fn step_equation(x: f64) -> f64 {
+    if x > 0.5 {
+        f64::NAN
+    } else {
+        1.
+    }
+}
+
+#[test]
+fn calculate_single_integral_simpson_propagate_non_finite() {
+    let result = calculate_single_integral_simpson(step_equation, 0., 1., 0.25).unwrap();
+
+    assert!(result.is_nan());
+}
+
+#[test]
+fn calculate_single_integral_simpson_error_on_non_finite() {
+    let result = calculate_single_integral_simpson_with_policy(
+        step_equation,
+        0.,
+        1.,
+        0.25,
+        NonFinitePolicy::Error,
+    );
+
+    match result {
+        Err(Error::NonFiniteIntegrand { point, .. }) => assert_eq!(point, vec![0.75]),
+        result => panic!("unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn calculate_single_integral_simpson_treat_non_finite_as_zero() {
+    let result = calculate_single_integral_simpson_with_policy(
+        step_equation,
+        0.,
+        1.,
+        0.25,
+        NonFinitePolicy::TreatAsZero,
+    )
+    .unwrap();
+
+    assert_approx_eq!(result, 0.5 + 0.25 / 3., 1e-12);
+}
+
+#[test]
+fn calculate_single_integral_simpson_skip_non_finite_point() {
+    let result = calculate_single_integral_simpson_with_policy(
+        step_equation,
+        0.,
+        1.,
+        0.25,
+        NonFinitePolicy::SkipPoint,
+    )
+    .unwrap();
+
+    assert_approx_eq!(result, 1., 1e-12);
+}
+
+#[test]
+fn calculate_single_integral_simpson_skip_whole_panel() {
+    let equation = |x: f64| if x > 0.4 { f64::NAN } else { 1. };
+
+    let result = calculate_single_integral_simpson_with_policy(
+        equation,
+        0.,
+        1.,
+        0.25,
+        NonFinitePolicy::SkipPoint,
+    );
+
+    match result {
+        Err(Error::NonFinitePanel { point, .. }) => assert_eq!(point, vec![0.5]),
+        result => panic!("expected NonFinitePanel, got {:?}", result),
+    }
+}
+
+#[test]
+fn calculate_double_integral_simpson_error_on_non_finite() {
+    let equation = |x: f64, y: f64| (x - y).sqrt();
+
+    let result = calculate_double_integral_simpson_with_policy(
+        equation,
+        0.,
+        1.,
+        0.1,
+        |_x| 0.,
+        |x| x + 0.1,
+        0.01,
+        NonFinitePolicy::Error,
+    );
+
+    match result {
+        Err(Error::NonFiniteIntegrand { point, .. }) => {
+            println!("point: {:?}", point);
+            assert_eq!(point.len(), 2);
+            assert!(point[1] > point[0]);
+        }
+        result => panic!("unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn calculate_triple_integral_simpson_error_on_non_finite() {
+    let equation = |x: f64, y: f64, z: f64| (1. - x.powf(2.) - y.powf(2.) - z.powf(2.)).sqrt();
+
+    let result = calculate_triple_integral_simpson_with_policy(
+        equation,
+        0.,
+        1.,
+        0.25,
+        |_x| 0.,
+        |_x| 1.,
+        0.25,
+        |_x, _y| 0.,
+        |_x, _y| 1.,
+        0.25,
+        NonFinitePolicy::Error,
+    );
+
+    match result {
+        Err(Error::NonFiniteIntegrand { point, .. }) => assert_eq!(point.len(), 3),
+        result => panic!("unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn calculate_double_integral_simpson_with_policy_same_as_simpson() {
+    let equation = |_x, _y| 1.;
+
+    for non_finite_policy in [
+        NonFinitePolicy::Propagate,
+        NonFinitePolicy::Error,
+        NonFinitePolicy::SkipPoint,
+        NonFinitePolicy::TreatAsZero,
+    ]
+    .iter()
+    {
+        let result = calculate_double_integral_simpson_with_policy(
+            equation,
+            0.,
+            1.,
+            0.1,
+            |_x| 0.,
+            |x| x,
+            0.1,
+            *non_finite_policy,
+        )
+        .unwrap();
+        let expected =
+            calculate_double_integral_simpson(equation, 0., 1., 0.1, |_x| 0., |x| x, 0.1).unwrap();
+
+        assert_eq!(result, expected);
+        assert_approx_eq!(result, 0.5, 1e-14);
+    }
+}
@@ -0,0 +1,86 @@
+#![cfg(feature = "parallel")]
+
+use sequential_integration::{
+    calculate_double_integral_simpson, calculate_double_integral_simpson_parallel,
+    calculate_single_integral_simpson, calculate_single_integral_simpson_parallel,
+    calculate_triple_integral_simpson, calculate_triple_integral_simpson_parallel, Resolution,
+};
+
+#[test]
+fn calculate_single_integral_simpson_parallel_same_as_sequential() {
+    let equation = |x: f64| (1. - x.powf(2.)).sqrt();
+
+    let result = calculate_single_integral_simpson_parallel(equation, 1., -1., 0.001).unwrap();
+    let expected = calculate_single_integral_simpson(equation, 1., -1., 0.001).unwrap();
+
+    assert_eq!(result.to_bits(), expected.to_bits());
+}
+
+#[test]
+fn calculate_double_integral_simpson_parallel_same_as_sequential() {
+    let equation = |x: f64, y: f64| x.powf(2.) / y.powf(2.);
+    let second_integral_begin = |x: f64| 1. / x;
+    let second_integral_end = |x: f64| x;
+
+    let result = calculate_double_integral_simpson_parallel(
+        equation,
+        1.,
+        2.,
+        0.01,
+        second_integral_begin,
+        second_integral_end,
+        Resolution::Panels(50),
+    )
+    .unwrap();
+    let expected = calculate_double_integral_simpson(
+        equation,
+        1.,
+        2.,
+        0.01,
+        second_integral_begin,
+        second_integral_end,
+        Resolution::Panels(50),
+    )
+    .unwrap();
+
+    assert_eq!(result.to_bits(), expected.to_bits());
+}
+
+#[test]
+fn calculate_triple_integral_simpson_parallel_same_as_sequential() {
+    let equation = |x: f64, y: f64, z: f64| x.powf(2.) + y.powf(2.) + z.powf(2.);
+
+    let result = calculate_triple_integral_simpson_parallel(
+        equation,
+        0.,
+        1.,
+        0.01,
+        |_x| 0.,
+        |x| x,
+        0.01,
+        |_x, _y| 0.,
+        |x, y| x + y,
+        0.01,
+    )
+    .unwrap();
+    let expected = calculate_triple_integral_simpson(
+        equation,
+        0.,
+        1.,
+        0.01,
+        |_x| 0.,
+        |x| x,
+        0.01,
+        |_x, _y| 0.,
+        |x, y| x + y,
+        0.01,
+    )
+    .unwrap();
+
+    assert_eq!(result.to_bits(), expected.to_bits());
+}
+
+#[test]
+fn calculate_single_integral_simpson_parallel_invalid_step() {
+    assert!(calculate_single_integral_simpson_parallel(|x: f64| x, 0., 1., 0.).is_err());
+}
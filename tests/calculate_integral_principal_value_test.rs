@@ -0,0 +1,140 @@
+use assert_approx_eq::assert_approx_eq;
+use sequential_integration::{
+    calculate_single_integral_principal_value, engine,
+    engine::quadrature::{gauss_kronrod::GaussKronrodRule, simpson::AdaptiveSimpsonQuadrature},
+    errors::Error,
+};
+
+#[test]
+fn calculate_single_integral_principal_value_polynomial() {
+    let equation = |x: f64| x.powi(2);
+
+    let result = calculate_single_integral_principal_value(
+        equation,
+        -1.,
+        1.,
+        0.5,
+        1e-12,
+        0.,
+        GaussKronrodRule::G7K15,
+        50,
+    )
+    .unwrap();
+    // x + c over the bounds plus c² ln((1 - c) / (1 + c))
+    let expected = 1. + 0.25 * (1f64 / 3.).ln();
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        expected,
+        result - expected
+    );
+    assert_approx_eq!(result, expected, 1e-12);
+}
+
+#[test]
+fn calculate_single_integral_principal_value_exponential() {
+    let equation = |x: f64| x.exp();
+
+    let result = calculate_single_integral_principal_value(
+        equation,
+        0.,
+        3.,
+        1.,
+        1e-12,
+        0.,
+        GaussKronrodRule::G10K21,
+        50,
+    )
+    .unwrap();
+    // e (Ei(2) - Ei(-1))
+    let expected = 14.063352586170633;
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        expected,
+        result - expected
+    );
+    assert_approx_eq!(result, expected, 1e-10);
+}
+
+#[test]
+fn calculate_single_integral_principal_value_symmetric() {
+    let result = calculate_single_integral_principal_value(
+        |_x| 1.,
+        -2.,
+        2.,
+        0.,
+        1e-12,
+        0.,
+        GaussKronrodRule::G7K15,
+        50,
+    )
+    .unwrap();
+    assert_approx_eq!(result, 0., 1e-14);
+
+    let result = calculate_single_integral_principal_value(
+        |x: f64| x.powi(2),
+        1.,
+        -1.,
+        0.5,
+        1e-12,
+        0.,
+        GaussKronrodRule::G7K15,
+        50,
+    )
+    .unwrap();
+    assert_approx_eq!(result, -(1. + 0.25 * (1f64 / 3.).ln()), 1e-12);
+}
+
+#[test]
+fn calculate_principal_value_with_rule_evaluating_pole() {
+    let adaptive_simpson_quadrature = AdaptiveSimpsonQuadrature::new(1e-10, 0.).unwrap();
+
+    let result = engine::calculate_principal_value_with_rule(
+        adaptive_simpson_quadrature,
+        |x: f64| x.exp(),
+        0.,
+        3.,
+        1.,
+    )
+    .unwrap();
+
+    assert_approx_eq!(result, 14.063352586170633, 1e-8);
+}
+
+#[test]
+fn calculate_single_integral_principal_value_invalid_pole() {
+    let equation = |x: f64| x;
+
+    for pole in [0., 1., 2., -1., f64::NAN] {
+        match calculate_single_integral_principal_value(
+            equation,
+            0.,
+            1.,
+            pole,
+            1e-10,
+            0.,
+            GaussKronrodRule::G7K15,
+            50,
+        ) {
+            Err(Error::InvalidPole { .. }) => {}
+            result => panic!("expected InvalidPole, got {:?}", result),
+        }
+    }
+
+    match calculate_single_integral_principal_value(
+        equation,
+        0.,
+        f64::INFINITY,
+        1.,
+        1e-10,
+        0.,
+        GaussKronrodRule::G7K15,
+        50,
+    ) {
+        Err(Error::NonFiniteBound { .. }) => {}
+        result => panic!("expected NonFiniteBound, got {:?}", result),
+    }
+}
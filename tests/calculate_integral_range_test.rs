@@ -0,0 +1,67 @@
+use assert_approx_eq::assert_approx_eq;
+use sequential_integration::{
+    calculate_single_integral_simpson, calculate_single_integral_simpson_with_report,
+    engine::{range_generator::RangeGenerator, Bounds, IndexRangeGenerator},
+};
+
+#[test]
+fn calculate_single_integral_simpson_panel_count_without_drift() {
+    let equation = |x: f64| x;
+
+    let outcome = calculate_single_integral_simpson_with_report(equation, 0., 1000., 0.1).unwrap();
+
+    assert_eq!(outcome.panels, 5000);
+    assert_approx_eq!(outcome.value, 500000., 1e-6);
+}
+
+#[test]
+fn calculate_single_integral_simpson_panel_count_not_binary_fraction() {
+    let equation = |x: f64| x.powf(2.);
+
+    let outcome = calculate_single_integral_simpson_with_report(equation, 0.3, 0.7, 0.1).unwrap();
+
+    assert_eq!(outcome.panels, 2);
+    assert_approx_eq!(outcome.value, (0.343 - 0.027) / 3., 1e-15);
+}
+
+#[test]
+fn calculate_single_integral_simpson_remainder_distributed() {
+    let equation = |x: f64| x.powf(3.);
+
+    let outcome = calculate_single_integral_simpson_with_report(equation, 0., 1., 0.3).unwrap();
+
+    assert_eq!(outcome.panels, 2);
+    assert_approx_eq!(outcome.value, 0.25, 1e-15);
+}
+
+#[test]
+fn index_range_generator_no_tiny_last_panel() {
+    let bounds = Bounds::new(0., 1. + 1e-8).unwrap();
+    let mut range = IndexRangeGenerator::new(bounds, 0.2).unwrap().unwrap();
+
+    let mut steps = Vec::new();
+    loop {
+        let step = range.next().unwrap();
+        steps.push(*step);
+        if step.is_last() {
+            break;
+        }
+    }
+    steps.push(bounds.end);
+
+    assert_eq!(steps.len(), 7);
+    for pair in steps.windows(2) {
+        assert_approx_eq!(pair[1] - pair[0], (1. + 1e-8) / 6., 1e-15);
+    }
+}
+
+#[test]
+fn calculate_single_integral_simpson_reproducible_for_reversed_bounds() {
+    let equation = |x: f64| x.sin();
+
+    let result = calculate_single_integral_simpson(equation, 0., 10., 0.001).unwrap();
+    let reversed_result = calculate_single_integral_simpson(equation, 10., 0., 0.001).unwrap();
+
+    assert_eq!(result, -reversed_result);
+    assert_approx_eq!(result, 1. - 10f64.cos(), 1e-12);
+}
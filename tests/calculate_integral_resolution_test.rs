@@ -0,0 +1,146 @@
+use assert_approx_eq::assert_approx_eq;
+use sequential_integration::{
+    calculate_double_integral_gauss_legendre, calculate_double_integral_simpson,
+    calculate_double_integral_simpson_with_report, calculate_single_integral_simpson,
+    calculate_single_integral_simpson_with_report, calculate_triple_integral_simpson,
+    errors::Error, Resolution,
+};
+
+#[test]
+fn calculate_single_integral_simpson_panels() {
+    let equation = |x: f64| x.powf(3.);
+
+    let outcome =
+        calculate_single_integral_simpson_with_report(equation, 0., 3., Resolution::Panels(8))
+            .unwrap();
+
+    assert_eq!(outcome.panels, 8);
+    assert_approx_eq!(outcome.value, 81. / 4., 1e-12);
+}
+
+#[test]
+fn calculate_single_integral_simpson_with_report_panels_richardson() {
+    let equation = |x: f64| x.powi(4);
+
+    let outcome =
+        calculate_single_integral_simpson_with_report(equation, 0., 1., Resolution::Panels(2))
+            .unwrap();
+
+    println!(
+        "outcome: {:?}, expected: {}, diff: {}",
+        outcome,
+        0.2,
+        outcome.value - 0.2
+    );
+    assert_approx_eq!(outcome.error, (outcome.value - 0.2).abs(), 1e-15);
+
+    for panels in [1, 3].iter() {
+        match calculate_single_integral_simpson_with_report(
+            equation,
+            0.,
+            1.,
+            Resolution::Panels(*panels),
+        ) {
+            Err(Error::OddPanelCount { .. }) => {}
+            result => panic!("expected OddPanelCount for {}, got {:?}", panels, result),
+        }
+    }
+}
+
+#[test]
+fn calculate_single_integral_simpson_zero_panels() {
+    let equation = |x: f64| x;
+
+    match calculate_single_integral_simpson(equation, 0., 1., Resolution::Panels(0)) {
+        Err(Error::ZeroPanelCount { .. }) => {}
+        result => panic!("expected ZeroPanelCount, got {:?}", result),
+    }
+}
+
+#[test]
+fn calculate_double_integral_simpson_panels_per_slice() {
+    let equation = |_x, _y| 1.;
+    let second_integral_begin = |_x| -0.;
+    let second_integral_end = |x: f64| (1. - x.powf(2.)).sqrt();
+
+    let result = calculate_double_integral_simpson(
+        equation,
+        -1.,
+        1.,
+        0.005,
+        second_integral_begin,
+        second_integral_end,
+        Resolution::Panels(20),
+    )
+    .unwrap();
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        std::f64::consts::FRAC_PI_2,
+        result - std::f64::consts::FRAC_PI_2
+    );
+
+    assert_approx_eq!(result, std::f64::consts::FRAC_PI_2, 1e-2);
+}
+
+#[test]
+fn calculate_double_integral_simpson_panels_narrow_slices() {
+    let equation = |_x: f64, y: f64| y;
+    let second_integral_begin = |_x| 0.;
+    let second_integral_end = |x: f64| 1e-3 * (1. + x);
+
+    let outcome = calculate_double_integral_simpson_with_report(
+        equation,
+        0.,
+        1.,
+        Resolution::Panels(10),
+        second_integral_begin,
+        second_integral_end,
+        Resolution::Panels(2),
+    )
+    .unwrap();
+
+    assert_eq!(outcome.panels, 21 * 2);
+    assert!(outcome.value > 0.);
+}
+
+#[test]
+fn calculate_double_integral_gauss_legendre_panels() {
+    let equation = |x: f64, y: f64| (x * y).exp();
+
+    let result = calculate_double_integral_gauss_legendre(
+        equation,
+        0.,
+        1.,
+        Resolution::Panels(2),
+        |_x| 0.,
+        |_x| 2.,
+        Resolution::Panels(4),
+        8,
+    )
+    .unwrap();
+
+    assert_approx_eq!(result, 3.68387151054041, 1e-12);
+}
+
+#[test]
+fn calculate_triple_integral_simpson_mixed_resolution() {
+    let equation = |x: f64, y: f64, z: f64| x + y + z;
+
+    let result = calculate_triple_integral_simpson(
+        equation,
+        0.,
+        1.,
+        0.25,
+        |_x| 0.,
+        |_x| 2.,
+        Resolution::Panels(3),
+        |_x, _y| 0.,
+        |_x, _y| 3.,
+        Resolution::Step(0.1),
+    )
+    .unwrap();
+
+    assert_approx_eq!(result, 6. * 3., 1e-10);
+}
@@ -0,0 +1,197 @@
+use assert_approx_eq::assert_approx_eq;
+use sequential_integration::{
+    calculate_double_integral_romberg, calculate_single_integral_romberg,
+    calculate_single_integral_romberg_with_diagonal, calculate_triple_integral_romberg,
+    engine::{
+        self,
+        quadrature::{romberg::RombergQuadrature, QuadratureRule},
+    },
+    errors::Error,
+    DimensionBounds, Integral, Rule,
+};
+
+#[test]
+fn calculate_single_integral_romberg_exp() {
+    let equation = |x: f64| x.exp();
+
+    let result = calculate_single_integral_romberg(equation, 0., 1., 1e-13, 0., 20).unwrap();
+    let expected = std::f64::consts::E - 1.;
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        expected,
+        result - expected
+    );
+    assert_approx_eq!(result, expected, 1e-13);
+}
+
+#[test]
+fn calculate_single_integral_romberg_diagonal() {
+    let equation = |x: f64| x.sin();
+
+    let (result, diagonal) = calculate_single_integral_romberg_with_diagonal(
+        equation,
+        std::f64::consts::PI,
+        0.,
+        1e-12,
+        0.,
+        20,
+    )
+    .unwrap();
+
+    println!("diagonal: {:?}", diagonal);
+    assert_approx_eq!(result, -2., 1e-12);
+    assert_eq!(*diagonal.last().unwrap(), result);
+    assert!(diagonal.len() <= 8);
+    assert!((diagonal[2] + 2.).abs() < (diagonal[1] + 2.).abs());
+}
+
+#[test]
+fn calculate_single_integral_romberg_polynomial_exact() {
+    let equation = |x: f64| x.powi(5) - 2. * x.powi(3);
+
+    let (result, diagonal) =
+        calculate_single_integral_romberg_with_diagonal(equation, 0., 2., 1e-12, 0., 20).unwrap();
+
+    // R(n, n) is exact for polynomials of degree 2n + 1
+    assert_eq!(diagonal.len(), 4);
+    assert_approx_eq!(result, 64. / 6. - 8., 1e-12);
+}
+
+#[test]
+fn calculate_double_integral_romberg_polynomial() {
+    let equation = |x: f64, y: f64| x.powf(2.) * y;
+
+    let result =
+        calculate_double_integral_romberg(equation, 0., 1., |_x| 0., |x| x, 1e-12, 0., 20).unwrap();
+
+    assert_approx_eq!(result, 0.1, 1e-12);
+}
+
+#[test]
+fn calculate_triple_integral_romberg_exp() {
+    let equation = |x: f64, y: f64, z: f64| (x + y + z).exp();
+
+    let result = calculate_triple_integral_romberg(
+        equation,
+        0.,
+        1.,
+        |_x| 0.,
+        |_x| 1.,
+        |_x, _y| 0.,
+        |_x, _y| 1.,
+        1e-10,
+        0.,
+        20,
+    )
+    .unwrap();
+    let expected = (std::f64::consts::E - 1.).powi(3);
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        expected,
+        result - expected
+    );
+    assert_approx_eq!(result, expected, 1e-9);
+}
+
+#[test]
+fn calculate_double_integral_builder_romberg_rule() {
+    let result = Integral::new(|x: f64, y: f64| (x * y).cos())
+        .over_x(0., 1.)
+        .over_y(|_x| 0., |_x| 1.)
+        .with_rule(Rule::Romberg {
+            abs_tol: 1e-12,
+            rel_tol: 0.,
+            max_level: 20,
+        })
+        .compute()
+        .unwrap();
+    let expected = calculate_double_integral_romberg(
+        |x: f64, y: f64| (x * y).cos(),
+        0.,
+        1.,
+        |_x| 0.,
+        |_x| 1.,
+        1e-12,
+        0.,
+        20,
+    )
+    .unwrap();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn calculate_single_integral_romberg_level_limit() {
+    let equation = |x: f64| x.sqrt();
+
+    match calculate_single_integral_romberg(equation, 0., 1., 1e-14, 0., 5) {
+        Err(Error::LevelLimitReached { limit: 5, .. }) => {}
+        result => panic!("expected LevelLimitReached, got {:?}", result),
+    }
+}
+
+#[test]
+fn calculate_single_integral_romberg_invalid_level() {
+    let equation = |x: f64| x;
+
+    for max_level in [0, 1, 31, usize::MAX].iter() {
+        match calculate_single_integral_romberg(equation, 0., 1., 1e-10, 0., *max_level) {
+            Err(Error::InvalidLevel {
+                level,
+                min: 2,
+                max: 30,
+                ..
+            }) => assert_eq!(level, *max_level),
+            result => panic!("expected InvalidLevel for {}, got {:?}", max_level, result),
+        }
+    }
+}
+
+#[test]
+fn calculate_double_integral_romberg_shared_instance() {
+    let romberg_quadrature = RombergQuadrature::new(1e-10, 0., 20).unwrap();
+
+    let result = engine::calculate_double_integral_with_rules(
+        &romberg_quadrature,
+        &romberg_quadrature,
+        |x: f64, y: f64| x * y,
+        0.,
+        1.,
+        |_x| 0.,
+        |x| x,
+    )
+    .unwrap();
+    assert_approx_eq!(result, 0.125, 1e-10);
+
+    let dimensions = [
+        DimensionBounds::constant(0., 1.),
+        DimensionBounds::constant(0., 2.),
+    ];
+    let result = engine::calculate_multiple_integral_with_rule(
+        &romberg_quadrature,
+        |point: &[f64; 2]| point[0] * point[1],
+        &dimensions,
+    )
+    .unwrap();
+    assert_approx_eq!(result, 1., 1e-10);
+}
+
+#[test]
+fn romberg_quadrature_diagonal_on_level_limit() {
+    let romberg_quadrature = RombergQuadrature::new(1e-14, 0., 5).unwrap();
+    let bounds = engine::Bounds::new(0., 1.).unwrap();
+
+    match romberg_quadrature.integrate(|x: f64| Ok(x.sqrt()), bounds) {
+        Err(Error::LevelLimitReached { limit: 5, .. }) => {}
+        result => panic!("expected LevelLimitReached, got {:?}", result),
+    }
+
+    let diagonal = romberg_quadrature.diagonal();
+    println!("diagonal: {:?}", diagonal);
+    assert_eq!(diagonal.len(), 6);
+    assert_approx_eq!(diagonal[5], 2. / 3., 1e-3);
+}
@@ -0,0 +1,114 @@
+use assert_approx_eq::assert_approx_eq;
+use sequential_integration::{
+    calculate_double_integral_simpson_with_report, calculate_single_integral_simpson,
+    calculate_single_integral_simpson_with_report, calculate_triple_integral_simpson_with_report,
+    errors::Error,
+};
+
+#[test]
+fn calculate_single_integral_simpson_with_report_error_estimate() {
+    let equation = |x: f64| x.sin();
+
+    let outcome = calculate_single_integral_simpson_with_report(equation, 0., 1., 0.0625).unwrap();
+    let expected = 1. - 1f64.cos();
+
+    println!(
+        "outcome: {:?}, expected: {}, diff: {}",
+        outcome,
+        expected,
+        outcome.value - expected
+    );
+
+    assert!(outcome.error > 0.);
+    assert!((outcome.value - expected).abs() <= 2. * outcome.error);
+    assert_eq!(outcome.panels, 8);
+    assert_eq!(outcome.evaluations, 17 + 9);
+}
+
+#[test]
+fn calculate_single_integral_simpson_with_report_same_value() {
+    let equation = |x: f64| (1. - x.powf(2.)).sqrt();
+
+    let value = calculate_single_integral_simpson(equation, -1., 1., 0.05).unwrap();
+    let outcome = calculate_single_integral_simpson_with_report(equation, -1., 1., 0.05).unwrap();
+
+    assert_eq!(outcome.value, value);
+}
+
+#[test]
+fn calculate_double_integral_simpson_with_report_error_estimate() {
+    let equation = |x: f64, y: f64| (x * y).exp();
+
+    let outcome = calculate_double_integral_simpson_with_report(
+        equation,
+        0.,
+        1.,
+        0.125,
+        |_x| 0.,
+        |_x| 2.,
+        0.125,
+    )
+    .unwrap();
+    let expected = 3.68387151054041;
+
+    println!(
+        "outcome: {:?}, expected: {}, diff: {}",
+        outcome,
+        expected,
+        outcome.value - expected
+    );
+
+    assert_approx_eq!(outcome.value, expected, 1e-4);
+    assert!((outcome.value - expected).abs() <= 2. * outcome.error);
+    assert_eq!(outcome.panels, 9 * 8);
+    assert_eq!(outcome.evaluations, 9 * 17 + 5 * 9);
+}
+
+#[test]
+fn calculate_triple_integral_simpson_with_report_cube() {
+    let equation = |x: f64, y: f64, z: f64| x * y * z;
+
+    let outcome = calculate_triple_integral_simpson_with_report(
+        equation,
+        0.,
+        1.,
+        0.25,
+        |_x| 0.,
+        |_x| 1.,
+        0.25,
+        |_x, _y| 0.,
+        |_x, _y| 1.,
+        0.25,
+    )
+    .unwrap();
+
+    assert_approx_eq!(outcome.value, 0.125, 1e-12);
+    assert!(outcome.error < 1e-12);
+    assert_eq!(outcome.panels, 5 * 5 * 2);
+    assert_eq!(outcome.evaluations, 5 * 5 * 5 + 3 * 3 * 3);
+}
+
+#[test]
+fn calculate_single_integral_simpson_with_report_odd_panel_count() {
+    let equation = |x: f64| x.exp();
+
+    match calculate_single_integral_simpson_with_report(equation, 0., 1., 0.1) {
+        Err(Error::OddPanelCount { panels: 5, .. }) => {}
+        result => panic!("expected OddPanelCount, got {:?}", result),
+    }
+
+    let outcome = calculate_single_integral_simpson_with_report(equation, 0., 1., 0.125).unwrap();
+    let expected = 1f64.exp() - 1.;
+
+    println!(
+        "outcome: {:?}, expected: {}, diff: {}",
+        outcome,
+        expected,
+        outcome.value - expected
+    );
+
+    assert!((outcome.value - expected).abs() <= 2. * outcome.error);
+    assert!(outcome.error <= 2. * (outcome.value - expected).abs());
+    assert_eq!(outcome.panels, 4);
+    assert_eq!(outcome.evaluations, 9 + 5);
+}
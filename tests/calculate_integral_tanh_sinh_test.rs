@@ -0,0 +1,151 @@
+use assert_approx_eq::assert_approx_eq;
+use sequential_integration::{
+    calculate_double_integral_tanh_sinh, calculate_single_integral_tanh_sinh,
+    calculate_triple_integral_tanh_sinh, errors::Error, Integral, Rule,
+};
+
+#[test]
+fn calculate_single_integral_tanh_sinh_inverse_sqrt() {
+    let equation = |x: f64| 1. / x.sqrt();
+
+    let result = calculate_single_integral_tanh_sinh(equation, 0., 1., 1e-12, 0., 10).unwrap();
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        2.,
+        result - 2.
+    );
+    assert_approx_eq!(result, 2., 1e-10);
+}
+
+#[test]
+fn calculate_single_integral_tanh_sinh_log() {
+    let equation = |x: f64| x.ln();
+
+    let result = calculate_single_integral_tanh_sinh(equation, 0., 1., 1e-12, 0., 10).unwrap();
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        -1.,
+        result + 1.
+    );
+    assert_approx_eq!(result, -1., 1e-10);
+}
+
+#[test]
+fn calculate_single_integral_tanh_sinh_never_evaluates_bounds() {
+    let equation = |x: f64| {
+        if x <= 1. || x >= 2. {
+            f64::NAN
+        } else {
+            1. / ((x - 1.) * (2. - x)).sqrt()
+        }
+    };
+
+    let result = calculate_single_integral_tanh_sinh(equation, 2., 1., 1e-8, 0., 10).unwrap();
+
+    assert_approx_eq!(result, -std::f64::consts::PI, 1e-6);
+}
+
+#[test]
+fn calculate_double_integral_tanh_sinh_hemisphere() {
+    let equation = |x: f64, y: f64| (1. - x.powi(2) - y.powi(2)).max(0.).sqrt();
+
+    let result = calculate_double_integral_tanh_sinh(
+        equation,
+        -1.,
+        1.,
+        |x: f64| -(1. - x.powi(2)).sqrt(),
+        |x: f64| (1. - x.powi(2)).sqrt(),
+        1e-10,
+        0.,
+        10,
+    )
+    .unwrap();
+    let expected = 2. * std::f64::consts::PI / 3.;
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        expected,
+        result - expected
+    );
+    assert_approx_eq!(result, expected, 1e-8);
+}
+
+#[test]
+fn calculate_triple_integral_tanh_sinh_ball() {
+    let equation = |_x: f64, _y: f64, _z: f64| 1.;
+
+    let result = calculate_triple_integral_tanh_sinh(
+        equation,
+        -1.,
+        1.,
+        |x: f64| -(1. - x.powi(2)).sqrt(),
+        |x: f64| (1. - x.powi(2)).sqrt(),
+        |x: f64, y: f64| -(1. - x.powi(2) - y.powi(2)).max(0.).sqrt(),
+        |x: f64, y: f64| (1. - x.powi(2) - y.powi(2)).max(0.).sqrt(),
+        1e-9,
+        0.,
+        10,
+    )
+    .unwrap();
+    let expected = 4. * std::f64::consts::PI / 3.;
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        expected,
+        result - expected
+    );
+    assert_approx_eq!(result, expected, 1e-7);
+}
+
+#[test]
+fn calculate_double_integral_builder_tanh_sinh_inner_rule() {
+    let result = Integral::new(|x: f64, y: f64| (1. - x.powi(2) - y.powi(2)).max(0.).sqrt())
+        .over_x(-1., 1.)
+        .over_y(|x| -(1. - x.powi(2)).sqrt(), |x| (1. - x.powi(2)).sqrt())
+        .with_rule(Rule::TanhSinh {
+            abs_tol: 1e-10,
+            rel_tol: 0.,
+            max_level: 10,
+        })
+        .with_x_rule(Rule::AdaptiveSimpson {
+            abs_tol: 1e-10,
+            rel_tol: 0.,
+        })
+        .compute()
+        .unwrap();
+
+    assert_approx_eq!(result, 2. * std::f64::consts::PI / 3., 1e-7);
+}
+
+#[test]
+fn calculate_single_integral_tanh_sinh_level_limit() {
+    let equation = |x: f64| (1. / x).sin();
+
+    match calculate_single_integral_tanh_sinh(equation, 0., 1., 1e-14, 0., 3) {
+        Err(Error::LevelLimitReached { limit: 3, .. }) => {}
+        result => panic!("expected LevelLimitReached, got {:?}", result),
+    }
+}
+
+#[test]
+fn calculate_single_integral_tanh_sinh_invalid_level() {
+    let equation = |x: f64| x;
+
+    for max_level in [0, 21, usize::MAX].iter() {
+        match calculate_single_integral_tanh_sinh(equation, 0., 1., 1e-10, 0., *max_level) {
+            Err(Error::InvalidLevel {
+                level,
+                min: 1,
+                max: 20,
+                ..
+            }) => assert_eq!(level, *max_level),
+            result => panic!("expected InvalidLevel for {}, got {:?}", max_level, result),
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use assert_approx_eq::assert_approx_eq;
+use sequential_integration::{
+    calculate_double_integral_midpoint, calculate_double_integral_trapezoid,
+    calculate_single_integral_midpoint, calculate_single_integral_trapezoid,
+    calculate_triple_integral_midpoint, calculate_triple_integral_trapezoid, Resolution,
+};
+
+#[test]
+fn calculate_single_integral_trapezoid_periodic() {
+    let equation = |x: f64| x.cos().exp();
+
+    let result = calculate_single_integral_trapezoid(
+        equation,
+        0.,
+        2. * std::f64::consts::PI,
+        Resolution::Panels(16),
+    )
+    .unwrap();
+    // 2π I0(1)
+    let expected = 7.954926521012845;
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        expected,
+        result - expected
+    );
+    assert_approx_eq!(result, expected, 1e-13);
+}
+
+#[test]
+fn calculate_single_integral_trapezoid_second_order() {
+    let equation = |x: f64| x.powi(2);
+
+    let fine = calculate_single_integral_trapezoid(equation, 0., 1., 0.01).unwrap();
+    let coarse = calculate_single_integral_trapezoid(equation, 0., 1., 0.02).unwrap();
+
+    let ratio = (coarse - 1. / 3.) / (fine - 1. / 3.);
+    println!("fine: {}, coarse: {}, ratio: {}", fine, coarse, ratio);
+    assert_approx_eq!(ratio, 4., 1e-6);
+}
+
+#[test]
+fn calculate_single_integral_midpoint_endpoint_singularity() {
+    let equation = |x: f64| 1. / x.sqrt();
+
+    let result = calculate_single_integral_midpoint(equation, 0., 1., 0.0001).unwrap();
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        2.,
+        result - 2.
+    );
+    assert!(result.is_finite());
+    assert_approx_eq!(result, 2., 1e-2);
+}
+
+#[test]
+fn calculate_single_integral_midpoint_reversed_bounds() {
+    let equation = |x: f64| x;
+
+    let result = calculate_single_integral_midpoint(equation, 1., 0., 0.3).unwrap();
+
+    assert_approx_eq!(result, -0.5, 1e-12);
+}
+
+#[test]
+fn calculate_double_integral_trapezoid_bilinear_exact() {
+    let equation = |x: f64, y: f64| x * y + x - y;
+
+    let result =
+        calculate_double_integral_trapezoid(equation, 0., 2., 0.5, |_x| 0., |_x| 1., 0.25).unwrap();
+
+    assert_approx_eq!(result, 1. + 2. - 1., 1e-12);
+}
+
+#[test]
+fn calculate_double_integral_midpoint_triangle() {
+    let equation = |x: f64, y: f64| x.powf(2.) * y;
+
+    let result =
+        calculate_double_integral_midpoint(equation, 0., 1., 0.001, |_x| 0., |x| x, 0.001).unwrap();
+
+    println!(
+        "result: {}, expected: {}, diff: {}",
+        result,
+        0.1,
+        result - 0.1
+    );
+    assert_approx_eq!(result, 0.1, 1e-3);
+}
+
+#[test]
+fn calculate_triple_integral_trapezoid_and_midpoint_cube() {
+    let equation = |x: f64, y: f64, z: f64| x + y * z;
+
+    let trapezoid = calculate_triple_integral_trapezoid(
+        equation,
+        0.,
+        1.,
+        Resolution::Panels(3),
+        |_x| 0.,
+        |_x| 1.,
+        Resolution::Panels(3),
+        |_x, _y| 0.,
+        |_x, _y| 1.,
+        Resolution::Panels(3),
+    )
+    .unwrap();
+    let midpoint = calculate_triple_integral_midpoint(
+        equation,
+        0.,
+        1.,
+        Resolution::Panels(3),
+        |_x| 0.,
+        |_x| 1.,
+        Resolution::Panels(3),
+        |_x, _y| 0.,
+        |_x, _y| 1.,
+        Resolution::Panels(3),
+    )
+    .unwrap();
+
+    assert_approx_eq!(trapezoid, 0.75, 1e-12);
+    assert_approx_eq!(midpoint, 0.75, 1e-12);
+}
+
+#[test]
+fn calculate_double_integral_trapezoid_and_midpoint_triangle_area() {
+    let equation = |_x, _y| 1.;
+
+    let trapezoid =
+        calculate_double_integral_trapezoid(equation, 0., 1., 0.1, |_x| 0., |x| x, 0.1).unwrap();
+    let midpoint =
+        calculate_double_integral_midpoint(equation, 0., 1., 0.1, |_x| 0., |x| x, 0.1).unwrap();
+
+    assert_approx_eq!(trapezoid, 0.5, 1e-14);
+    assert_approx_eq!(midpoint, 0.5, 1e-14);
+}
+
+#[test]
+fn calculate_triple_integral_trapezoid_not_const_bounds_second_order() {
+    let equation = |x: f64, y: f64, z: f64| x * y * z;
+    let calculate = |step: f64| {
+        calculate_triple_integral_trapezoid(
+            equation,
+            0.,
+            1.,
+            step,
+            |_x| 0.,
+            |x| x,
+            step,
+            |_x, _y| 0.,
+            |x, y| x + y,
+            step,
+        )
+        .unwrap()
+    };
+
+    let fine = calculate(0.01);
+    let coarse = calculate(0.02);
+
+    let ratio = (coarse - 17. / 144.) / (fine - 17. / 144.);
+    println!("fine: {}, coarse: {}, ratio: {}", fine, coarse, ratio);
+    assert_approx_eq!(ratio, 4., 1e-2);
+}
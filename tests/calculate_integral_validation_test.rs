@@ -0,0 +1,120 @@
+use sequential_integration::{
+    calculate_double_integral_simpson, calculate_single_integral_gauss_legendre,
+    calculate_single_integral_simpson, calculate_triple_integral_simpson, errors::Error,
+};
+
+#[test]
+fn calculate_single_integral_simpson_non_positive_step() {
+    let equation = |x: f64| x;
+
+    for step in [0., -0., -0.01].iter() {
+        match calculate_single_integral_simpson(equation, 0., 1., *step) {
+            Err(Error::NonPositiveStep { .. }) => {}
+            result => panic!("expected NonPositiveStep for {}, got {:?}", step, result),
+        }
+    }
+}
+
+#[test]
+fn calculate_single_integral_simpson_non_finite_step() {
+    let equation = |x: f64| x;
+
+    for step in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY].iter() {
+        match calculate_single_integral_simpson(equation, 0., 1., *step) {
+            Err(Error::NonFiniteStep { .. }) => {}
+            result => panic!("expected NonFiniteStep for {}, got {:?}", step, result),
+        }
+    }
+}
+
+#[test]
+fn calculate_single_integral_simpson_tiny_step() {
+    let equation = |x: f64| x;
+
+    for step in [1e-300, f64::MIN_POSITIVE].iter() {
+        match calculate_single_integral_simpson(equation, 0., 1., *step) {
+            Err(Error::TooManyPanels { .. }) => {}
+            result => panic!("expected TooManyPanels for {}, got {:?}", step, result),
+        }
+    }
+}
+
+#[test]
+fn calculate_single_integral_simpson_non_finite_bound() {
+    let equation = |x: f64| x;
+
+    for (begin, end) in [
+        (f64::NAN, 1.),
+        (0., f64::NAN),
+        (f64::INFINITY, f64::INFINITY),
+    ]
+    .iter()
+    {
+        match calculate_single_integral_simpson(equation, *begin, *end, 0.1) {
+            Err(Error::NonFiniteBound { .. }) => {}
+            result => panic!(
+                "expected NonFiniteBound for [{}, {}], got {:?}",
+                begin, end, result
+            ),
+        }
+    }
+}
+
+#[test]
+fn calculate_double_integral_simpson_invalid_inner_step() {
+    let equation = |_x, _y| 1.;
+
+    match calculate_double_integral_simpson(equation, 0., 1., 0.1, |_x| 0., |_x| 1., 0.) {
+        Err(Error::NonPositiveStep { .. }) => {}
+        result => panic!("expected NonPositiveStep, got {:?}", result),
+    }
+}
+
+#[test]
+fn calculate_double_integral_simpson_non_finite_inner_bound() {
+    let equation = |_x, _y| 1.;
+
+    match calculate_double_integral_simpson(
+        equation,
+        0.,
+        1.,
+        0.1,
+        |_x| 0.,
+        |x: f64| (x - 2.).sqrt(),
+        0.1,
+    ) {
+        Err(Error::NonFiniteBound { .. }) => {}
+        result => panic!("expected NonFiniteBound, got {:?}", result),
+    }
+}
+
+#[test]
+fn calculate_triple_integral_simpson_non_finite_step() {
+    let equation = |_x, _y, _z| 1.;
+
+    match calculate_triple_integral_simpson(
+        equation,
+        0.,
+        1.,
+        0.1,
+        |_x| 0.,
+        |_x| 1.,
+        0.1,
+        |_x, _y| 0.,
+        |_x, _y| 1.,
+        f64::NAN,
+    ) {
+        Err(Error::NonFiniteStep { .. }) => {}
+        result => panic!("expected NonFiniteStep, got {:?}", result),
+    }
+}
+
+#[test]
+fn calculate_single_integral_gauss_legendre_non_positive_step() {
+    let equation = |x: f64| x;
+
+    match calculate_single_integral_gauss_legendre(equation, 0., 1., -1., 4) {
+        Err(Error::NonPositiveStep { .. }) => {}
+        result => panic!("expected NonPositiveStep, got {:?}", result),
+    }
+}